@@ -1,12 +1,17 @@
+mod projects;
+
 use anyhow::{Context, Result};
-use chrono::{DateTime, Utc};
+use chrono::{
+    format::{Item, StrftimeItems},
+    DateTime, Datelike, Local, SecondsFormat, Utc,
+};
 use clap::{Parser, Subcommand};
 use clipboard::{ClipboardContext, ClipboardProvider};
 use crossterm::{
     cursor::{Hide, MoveTo, Show},
-    event::{self, Event, KeyCode, KeyEvent, KeyModifiers},
+    event::{self, DisableBracketedPaste, EnableBracketedPaste, Event, KeyCode, KeyEvent, KeyModifiers},
     execute,
-    style::{Color, Print, ResetColor, SetForegroundColor},
+    style::{Attribute, Color, Print, ResetColor, SetAttribute, SetForegroundColor},
     terminal::{
         disable_raw_mode, enable_raw_mode, size, Clear, ClearType, EnterAlternateScreen,
         LeaveAlternateScreen,
@@ -16,19 +21,59 @@ use dirs::home_dir;
 use is_terminal::IsTerminal;
 use regex::Regex;
 use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
     env,
-    fs::{self, Metadata},
-    io::{self, Write},
-    path::PathBuf,
-    process::Command,
-    time::UNIX_EPOCH,
+    fs,
+    io::{self, BufRead, Read, Write},
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver, TryRecvError},
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
 };
 use url::Url;
 
+use projects::{
+    check_deletable, ensure_roots, load_index, rank_projects, reindex, save_index, scan_projects, scan_projects_with, score_breakdown,
+    slop_file_value, unsaved_work,
+    walk_dir, Discovery, Project,
+    ProjectType, ScanOptions,
+};
+
+static PLAIN_OUTPUT: AtomicBool = AtomicBool::new(false);
+
+/// `println!` for user-facing status lines; strips emoji under `--plain`.
+macro_rules! say {
+    () => {
+        println!()
+    };
+    ($($arg:tt)*) => {
+        println!("{}", decorate(&format!($($arg)*)))
+    };
+}
+
+/// `eprintln!` counterpart of `say!`.
+macro_rules! say_err {
+    () => {
+        eprintln!()
+    };
+    ($($arg:tt)*) => {
+        eprintln!("{}", decorate(&format!($($arg)*)))
+    };
+}
+
 #[derive(Parser)]
 #[command(name = "slop")]
 #[command(about = "Vibecoding at hyperspeed - create projects OR paste GitHub URLs to clone & launch in Claude!")]
 struct Cli {
+    /// Plain output without emoji, for logs, scripts, and screen readers
+    #[arg(long, global = true)]
+    plain: bool,
+
     #[command(subcommand)]
     command: Option<Commands>,
 }
@@ -42,15 +87,106 @@ enum Commands {
         path: Option<PathBuf>,
         /// Additional path argument (for backward compatibility)
         projects_path: Option<PathBuf>,
+        /// Shell to write the function for (default: powershell when run from PowerShell, else posix)
+        #[arg(long, value_parser = ["posix", "powershell"])]
+        shell: Option<String>,
     },
     /// Interactive project selector and creator - paste GitHub URLs to clone!
     Run {
         /// Path to projects directory
         #[arg(long)]
         path: Option<PathBuf>,
+        /// Only print errors (no clone/open status lines)
+        #[arg(short, long)]
+        quiet: bool,
+        /// Screen-reader friendly navigator that announces each move as plain text
+        #[arg(long)]
+        a11y: bool,
+        /// Read project names/URLs from stdin, one per line, and create or clone
+        /// each without opening an editor
+        #[arg(long, conflicts_with = "query")]
+        from_stdin: bool,
+        /// Clone with submodules (or set `recurse_submodules = true`)
+        #[arg(long)]
+        recurse_submodules: bool,
+        /// Open the project after cloning (`--open false` to clone only)
+        #[arg(long, default_value_t = true, action = clap::ArgAction::Set, value_name = "BOOL")]
+        open: bool,
+        /// Open this folder inside a cloned repo instead of its root (also `user/repo/path/to/sub`)
+        #[arg(long, value_name = "PATH")]
+        subdir: Option<String>,
+        /// Check out only that folder (a sparse clone; or set `sparse_subdir = true`)
+        #[arg(long)]
+        sparse: bool,
+        /// Start with the score breakdown panel open (F12 toggles it)
+        #[arg(long)]
+        debug: bool,
+        /// Open projects in this editor instead of default_editor, for this run only
+        #[arg(long, value_name = "CMD")]
+        editor: Option<String>,
         /// Project name to create/find OR GitHub URL to clone (user/repo, github.com/user/repo, or full URL)
         query: Vec<String>,
     },
+    /// Start a new project from a template repo: clone it, drop its history, `git init` afresh
+    New {
+        /// Path to projects directory
+        #[arg(long)]
+        path: Option<PathBuf>,
+        /// Name of the new project
+        name: String,
+        /// Template repo to start from (user/repo, github.com/user/repo, or full URL)
+        #[arg(long, value_name = "URL")]
+        from: String,
+        /// Leave `{{project-name}}` placeholders in the template's files as they are
+        #[arg(long)]
+        no_substitute: bool,
+        /// Open the project once it's created (`--open false` to skip)
+        #[arg(long, default_value_t = true, action = clap::ArgAction::Set, value_name = "BOOL")]
+        open: bool,
+    },
+    /// Delete a project by name without opening the navigator
+    #[command(name = "rm")]
+    Remove {
+        /// Path to projects directory
+        #[arg(long)]
+        path: Option<PathBuf>,
+        /// Name of the project to delete
+        name: String,
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Undo the last create, clone, fork, worktree or delete
+    Undo {
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Reopen the most recently used projects at once
+    Resume {
+        /// Path to projects directory
+        #[arg(long)]
+        path: Option<PathBuf>,
+        /// How many projects to open (defaults to resume_count in config)
+        count: Option<usize>,
+    },
+    /// Re-walk your home folder for git repos and rebuild the project index
+    Reindex,
+    /// Summarize project activity
+    Stats {
+        /// Path to projects directory
+        #[arg(long)]
+        path: Option<PathBuf>,
+        /// Print machine-readable JSON
+        #[arg(long)]
+        json: bool,
+        /// Summarize the local `activity_log` file instead (nothing is ever sent anywhere)
+        #[arg(long, conflicts_with = "path")]
+        from_log: bool,
+        /// Only count projects touched or created within this long, e.g. 7d, 2w, 12h
+        #[arg(long, value_name = "DURATION", value_parser = parse_since)]
+        since: Option<chrono::Duration>,
+    },
     /// Configure slop settings
     Config {
         #[command(subcommand)]
@@ -70,35 +206,43 @@ enum ConfigAction {
         /// Editor command (cursor, code, vim, etc.)
         editor: String,
     },
+    /// Map a short name to a repo URL (omit the URL to remove the alias)
+    Alias {
+        /// Name typed in the navigator
+        name: String,
+        /// Repo to clone (user/repo, github.com/user/repo, or full URL)
+        url: Option<String>,
+    },
     /// Show current configuration
-    Show,
+    Show {
+        /// Print the resolved configuration as JSON (same as `--format json`)
+        #[arg(long, conflicts_with = "format")]
+        json: bool,
+        /// Output format: text (default), json, or toml
+        #[arg(long, value_parser = ["text", "json", "toml"])]
+        format: Option<String>,
+        /// Show where the config and projects path come from, in the order they're checked
+        #[arg(long, conflicts_with_all = ["json", "format"])]
+        paths: bool,
+    },
+    /// Open the config file in $EDITOR
+    Edit,
     /// Reset configuration to defaults
     Reset,
 }
 
-#[derive(Debug, Clone)]
-struct Project {
-    name: String,
-    path: PathBuf,
-    last_accessed: DateTime<Utc>,
-    created: DateTime<Utc>,
-    score: f64,
-    project_type: ProjectType,
-}
-
-#[derive(Debug, Clone)]
-enum ProjectType {
-    Local,
-    GitRepo,
-}
-
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 enum ProjectTemplate {
     Rust,
     Python,
     JavaScript,
     TypeScript,
     Go,
+    OCaml,
+    Gleam,
+    Dart,
+    Devcontainer,
+    Docker,
     Blank,
 }
 
@@ -110,43 +254,582 @@ impl ProjectTemplate {
             Self::JavaScript,
             Self::TypeScript,
             Self::Go,
+            Self::OCaml,
+            Self::Gleam,
+            Self::Dart,
+            Self::Devcontainer,
+            Self::Docker,
             Self::Blank,
         ]
     }
 
-    fn display_name(&self) -> &str {
+    fn display_name(&self) -> &'static str {
         match self {
             Self::Rust => "Rust",
             Self::Python => "Python",
             Self::JavaScript => "JavaScript",
             Self::TypeScript => "TypeScript",
             Self::Go => "Go",
+            Self::OCaml => "OCaml",
+            Self::Gleam => "Gleam",
+            Self::Dart => "Dart",
+            Self::Devcontainer => "Devcontainer",
+            Self::Docker => "Docker",
             Self::Blank => "Blank",
         }
     }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::get_all()
+            .into_iter()
+            .find(|template| template.display_name().eq_ignore_ascii_case(name))
+    }
 }
 
 struct VibeSelector {
     cursor_pos: usize,
     scroll_offset: usize,
     input_buffer: String,
+    /// How many characters the text cursor sits before the end of `input_buffer`;
+    /// 0 keeps it at the end even when the buffer is replaced
+    input_cursor_back: usize,
     selected: Option<SelectionResult>,
     term_width: u16,
     term_height: u16,
     all_projects: Option<Vec<Project>>,
+    scan: Option<BackgroundScan>,
     base_path: PathBuf,
+    roots: Vec<PathBuf>,
     mode: SelectorMode,
-    delete_target: Option<usize>,
+    /// Path, not row: rows shift while a background scan is still adding projects
+    delete_target: Option<PathBuf>,
+    delete_warnings: Vec<String>,
+    delete_armed: bool,
+    /// The trash is on another filesystem, so the armed delete is permanent
+    delete_permanent: bool,
+    fork_source: Option<Project>,
+    worktree_source: Option<Project>,
+    /// Project being moved to another root, and the row it was on
+    move_source: Option<(Project, usize)>,
+    /// Clone waiting on its folder name, and the search text it came from
+    clone_pending: Option<(SelectionResult, String)>,
+    /// Project whose own editor is being set
+    editor_source: Option<Project>,
+    name_collision: Option<SelectionResult>,
+    status_message: Option<String>,
+    yanked_path: Option<PathBuf>,
+    /// Whether the first project load may come from the index
+    try_index: bool,
+    /// Projects marked with Tab, in the order they were marked
+    marked: Vec<PathBuf>,
+    /// Whether creates and clones ask which root to use when there are several
+    pick_destination: bool,
+    pending_destination: Option<SelectionResult>,
+    /// The `?` overlay is up; any key closes it
+    show_help: bool,
+    /// Text narrowing the template list, while `/` filtering is on
+    template_filter: Option<String>,
+    /// Show how the selected row's score is made up (F12 or `--debug`)
+    show_score: bool,
+    last_template: Option<ProjectTemplate>,
+    row_format: Vec<RowToken>,
+    /// `None` while a worker thread is still adding the project up
+    project_sizes: HashMap<PathBuf, Option<u64>>,
+    size_sender: mpsc::Sender<(PathBuf, u64)>,
+    sizes_found: Receiver<(PathBuf, u64)>,
+    /// Origin remote per git project, read once for duplicate detection
+    project_remotes: HashMap<PathBuf, Option<String>>,
+    theme: Theme,
+    keymap: Keymap,
+    config: VibeConfig,
 }
 
-#[derive(Debug, Clone)]
+/// What a piece of UI text means; the theme decides how it looks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Hue {
+    Header,
+    Selection,
+    Meta,
+    Success,
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Theme {
+    Dark,
+    Light,
+    Mono,
+}
+
+impl Theme {
+    fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "dark" => Some(Theme::Dark),
+            "light" => Some(Theme::Light),
+            "mono" => Some(Theme::Mono),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Theme::Dark => "dark",
+            Theme::Light => "light",
+            Theme::Mono => "mono",
+        }
+    }
+
+    /// The configured theme, unless NO_COLOR (https://no-color.org) is set
+    fn effective(self) -> Self {
+        if env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()) {
+            Theme::Mono
+        } else {
+            self
+        }
+    }
+
+    fn color(self, hue: Hue) -> Option<Color> {
+        match self {
+            Theme::Dark => Some(match hue {
+                Hue::Header => Color::Cyan,
+                Hue::Selection => Color::Yellow,
+                Hue::Meta => Color::DarkGrey,
+                Hue::Success => Color::Green,
+                Hue::Warning => Color::Yellow,
+                Hue::Error => Color::Red,
+            }),
+            // Dark variants stay readable on a white background
+            Theme::Light => Some(match hue {
+                Hue::Header => Color::DarkBlue,
+                Hue::Selection => Color::DarkMagenta,
+                Hue::Meta => Color::DarkGrey,
+                Hue::Success => Color::DarkGreen,
+                Hue::Warning => Color::DarkYellow,
+                Hue::Error => Color::DarkRed,
+            }),
+            Theme::Mono => None,
+        }
+    }
+}
+
+/// `SetForegroundColor` that does nothing when the theme has no color.
+struct Paint(Option<Color>);
+
+impl crossterm::Command for Paint {
+    fn write_ansi(&self, f: &mut impl std::fmt::Write) -> std::fmt::Result {
+        match self.0 {
+            Some(color) => SetForegroundColor(color).write_ansi(f),
+            None => Ok(()),
+        }
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> io::Result<()> {
+        match self.0 {
+            Some(color) => SetForegroundColor(color).execute_winapi(),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Navigator actions that `[keymap]` in the config can rebind.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum KeyAction {
+    Up,
+    Down,
+    Select,
+    Delete,
+    Config,
+    Quit,
+    Clear,
+    Mark,
+    Editor,
+}
+
+impl KeyAction {
+    const ALL: [KeyAction; 9] = [
+        KeyAction::Up,
+        KeyAction::Down,
+        KeyAction::Select,
+        KeyAction::Delete,
+        KeyAction::Config,
+        KeyAction::Quit,
+        KeyAction::Clear,
+        KeyAction::Mark,
+        KeyAction::Editor,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            KeyAction::Up => "up",
+            KeyAction::Down => "down",
+            KeyAction::Select => "select",
+            KeyAction::Delete => "delete",
+            KeyAction::Config => "config",
+            KeyAction::Quit => "quit",
+            KeyAction::Clear => "clear",
+            KeyAction::Mark => "mark",
+            KeyAction::Editor => "editor",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|action| action.name() == name)
+    }
+
+    fn default_keys(self) -> &'static [&'static str] {
+        match self {
+            KeyAction::Up => &["up", "ctrl+p"],
+            KeyAction::Down => &["down", "ctrl+n"],
+            KeyAction::Select => &["enter"],
+            KeyAction::Delete => &["delete", "d"],
+            KeyAction::Config => &[],
+            KeyAction::Quit => &["esc", "ctrl+c"],
+            KeyAction::Clear => &["esc", "ctrl+c"],
+            KeyAction::Mark => &["tab"],
+            KeyAction::Editor => &["ctrl+e"],
+        }
+    }
+}
+
+/// A key like `ctrl+p`, `f5`, `enter` or `d`. Modifiers must match exactly,
+/// except Shift on characters, which terminals report inconsistently.
+#[derive(Debug, Clone, PartialEq)]
+struct KeySpec {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeySpec {
+    fn parse(spec: &str) -> Option<Self> {
+        let mut modifiers = KeyModifiers::NONE;
+        let mut rest = spec.trim();
+        while let Some((modifier, key)) = rest.split_once('+').filter(|(_, key)| !key.is_empty()) {
+            modifiers |= match modifier.to_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" | "meta" | "option" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return None,
+            };
+            rest = key;
+        }
+
+        let code = match rest.to_lowercase().as_str() {
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "enter" | "return" => KeyCode::Enter,
+            "esc" | "escape" => KeyCode::Esc,
+            "tab" => KeyCode::Tab,
+            "backspace" => KeyCode::Backspace,
+            "delete" | "del" => KeyCode::Delete,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            "space" => KeyCode::Char(' '),
+            name if name.len() > 1 && name.starts_with('f') => KeyCode::F(name[1..].parse().ok()?),
+            _ => {
+                let mut chars = rest.chars();
+                let ch = chars.next()?;
+                if chars.next().is_some() {
+                    return None;
+                }
+                KeyCode::Char(ch)
+            }
+        };
+        Some(KeySpec { code, modifiers })
+    }
+
+    fn matches(&self, key: &KeyEvent) -> bool {
+        let ignored = if matches!(self.code, KeyCode::Char(_)) { KeyModifiers::SHIFT } else { KeyModifiers::NONE };
+        key.code == self.code && key.modifiers - ignored == self.modifiers - ignored
+    }
+
+    /// How the help and footers spell the key: `↑`, `Ctrl+P`, `D`, `F5`.
+    fn label(&self) -> String {
+        let key = match self.code {
+            KeyCode::Up => "↑".to_string(),
+            KeyCode::Down => "↓".to_string(),
+            KeyCode::Left => "←".to_string(),
+            KeyCode::Right => "→".to_string(),
+            KeyCode::Enter => "Enter".to_string(),
+            KeyCode::Esc => "Esc".to_string(),
+            KeyCode::Tab => "Tab".to_string(),
+            KeyCode::Backspace => "Backspace".to_string(),
+            KeyCode::Delete => "Delete".to_string(),
+            KeyCode::Home => "Home".to_string(),
+            KeyCode::End => "End".to_string(),
+            KeyCode::PageUp => "PageUp".to_string(),
+            KeyCode::PageDown => "PageDown".to_string(),
+            KeyCode::Char(' ') => "Space".to_string(),
+            KeyCode::Char(ch) => ch.to_uppercase().to_string(),
+            KeyCode::F(n) => format!("F{}", n),
+            _ => "?".to_string(),
+        };
+        let mut label = String::new();
+        for (modifier, name) in [(KeyModifiers::CONTROL, "Ctrl+"), (KeyModifiers::ALT, "Alt+"), (KeyModifiers::SHIFT, "Shift+")] {
+            if self.modifiers.contains(modifier) {
+                label.push_str(name);
+            }
+        }
+        label + &key
+    }
+}
+
+/// The navigator's key bindings: defaults, with `[keymap]` entries replacing
+/// an action's keys wholesale.
+struct Keymap {
+    bindings: Vec<(KeyAction, Vec<KeySpec>)>,
+}
+
+impl Keymap {
+    fn new(overrides: &BTreeMap<String, Vec<String>>) -> Self {
+        let bindings = KeyAction::ALL
+            .into_iter()
+            .map(|action| {
+                let specs = match overrides.get(action.name()) {
+                    Some(keys) => keys.iter().filter_map(|key| KeySpec::parse(key)).collect(),
+                    None => action.default_keys().iter().filter_map(|key| KeySpec::parse(key)).collect(),
+                };
+                (action, specs)
+            })
+            .collect();
+        Keymap { bindings }
+    }
+
+    fn is(&self, action: KeyAction, key: &KeyEvent, has_input: bool) -> bool {
+        self.bindings.iter().any(|(bound, specs)| {
+            *bound == action && !is_text_key(key, has_input) && specs.iter().any(|spec| spec.matches(key))
+        })
+    }
+
+    fn keys(&self, action: KeyAction) -> Vec<String> {
+        self.bindings
+            .iter()
+            .filter(|(bound, _)| *bound == action)
+            .flat_map(|(_, specs)| specs.iter().map(KeySpec::label))
+            .collect()
+    }
+
+    /// What `key` does. Clear and quit share Esc by default, so clear wins
+    /// while there's something to clear.
+    fn action(&self, key: &KeyEvent, has_input: bool) -> Option<KeyAction> {
+        if has_input && self.is(KeyAction::Clear, key, has_input) {
+            return Some(KeyAction::Clear);
+        }
+        KeyAction::ALL
+            .into_iter()
+            .filter(|action| *action != KeyAction::Clear)
+            .find(|action| self.is(*action, key, has_input))
+    }
+}
+
+/// One line of the `?` overlay. Footers show the ones marked `footer`, each
+/// with its shortest key, so the two can't drift apart.
+struct Shortcut {
+    keys: Vec<String>,
+    label: Cow<'static, str>,
+    footer: bool,
+}
+
+impl Shortcut {
+    fn new(keys: &[&str], label: &'static str, footer: bool) -> Self {
+        Shortcut { keys: keys.iter().map(|k| k.to_string()).collect(), label: label.into(), footer }
+    }
+}
+
+const NAVIGATE_KEYS: &[&str] = &["↑↓", "Ctrl+P/Ctrl+N"];
+const HELP_KEYS: &[&str] = &["?", "F1"];
+
+/// The navigator's one rule for letter keys: a bare (or shifted) character is
+/// a command only while the search is empty; once something is typed it's
+/// text. Every single-character binding, built in or from `[keymap]`, checks
+/// this so commands never eat characters of a name or URL.
+fn is_text_key(key: &KeyEvent, has_input: bool) -> bool {
+    has_input && matches!(key.code, KeyCode::Char(_)) && (key.modifiers - KeyModifiers::SHIFT).is_empty()
+}
+
+/// Set while the navigator owns the terminal, so it is restored exactly once
+/// whether that happens normally, on an error, or from the panic hook.
+static TERMINAL_ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Raw mode (plus the alternate screen outside a11y mode) for as long as it
+/// lives. Dropping it restores the terminal, so errors unwinding out of the
+/// navigator never leave a hidden cursor or a garbled screen behind.
+struct TerminalGuard {
+    alternate_screen: bool,
+}
+
+impl TerminalGuard {
+    fn enter(alternate_screen: bool) -> Result<Self> {
+        enable_raw_mode()?;
+        TERMINAL_ACTIVE.store(true, Ordering::SeqCst);
+        // Pastes then arrive as one event instead of a burst of keys
+        execute!(io::stderr(), EnableBracketedPaste)?;
+
+        // Panic messages print before unwinding starts, so restore first or
+        // they land on the alternate screen and vanish with it
+        static HOOK: std::sync::Once = std::sync::Once::new();
+        HOOK.call_once(|| {
+            let previous = std::panic::take_hook();
+            std::panic::set_hook(Box::new(move |info| {
+                let _ = TerminalGuard::leave(alternate_screen);
+                previous(info);
+            }));
+        });
+
+        Ok(TerminalGuard { alternate_screen })
+    }
+
+    fn restore(self) -> Result<()> {
+        Self::leave(self.alternate_screen)
+    }
+
+    fn leave(alternate_screen: bool) -> Result<()> {
+        if !TERMINAL_ACTIVE.swap(false, Ordering::SeqCst) {
+            return Ok(());
+        }
+        execute!(io::stderr(), DisableBracketedPaste)?;
+        if alternate_screen {
+            execute!(
+                io::stderr(),
+                Show,
+                LeaveAlternateScreen,
+                Clear(ClearType::All)
+            )?;
+        }
+        disable_raw_mode()?;
+        Ok(())
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = Self::leave(self.alternate_screen);
+    }
+}
+
+/// One piece of a parsed `row_format`.
+#[derive(Debug, Clone, PartialEq)]
+enum RowToken {
+    Text(String),
+    Icon,
+    Name,
+    Time,
+    Score,
+    Size,
+    Lang,
+    Root,
+    /// `|`: what follows is right-aligned
+    Align,
+}
+
+fn parse_row_format(format: &str) -> Vec<RowToken> {
+    let mut tokens = Vec::new();
+    let mut text = String::new();
+    let mut rest = format;
+
+    while let Some(ch) = rest.chars().next() {
+        let placeholder = if ch == '{' {
+            rest.find('}').and_then(|end| {
+                let token = match &rest[1..end] {
+                    "icon" => RowToken::Icon,
+                    "name" => RowToken::Name,
+                    "time" => RowToken::Time,
+                    "score" => RowToken::Score,
+                    "size" => RowToken::Size,
+                    "lang" => RowToken::Lang,
+                    "root" => RowToken::Root,
+                    _ => return None, // unknown placeholders stay literal
+                };
+                Some((token, end + 1))
+            })
+        } else if ch == '|' && !tokens.contains(&RowToken::Align) {
+            Some((RowToken::Align, 1))
+        } else {
+            None
+        };
+
+        match placeholder {
+            Some((token, len)) => {
+                if !text.is_empty() {
+                    tokens.push(RowToken::Text(std::mem::take(&mut text)));
+                }
+                tokens.push(token);
+                rest = &rest[len..];
+            }
+            None => {
+                text.push(ch);
+                rest = &rest[ch.len_utf8()..];
+            }
+        }
+    }
+    if !text.is_empty() {
+        tokens.push(RowToken::Text(text));
+    }
+    tokens
+}
+
+/// A scan running on its own thread, feeding projects in as they're found.
+struct BackgroundScan {
+    found: Receiver<Project>,
+    handle: JoinHandle<Result<()>>,
+    /// When refreshing a list loaded from the index, results collect here and
+    /// replace it once the scan is done
+    fresh: Option<Vec<Project>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
 enum SelectorMode {
     ProjectSelection,
     TemplateSelection,
     Configuration,
+    PickingEditor,
     EditingPath,
     EditingEditor,
     ConfirmDelete,
+    ConfirmCollision,
+    PickingDestination,
+    ForkName,
+    WorktreeBranch,
+    MovingProject,
+    CloneName,
+    ProjectEditor,
+}
+
+impl SelectorMode {
+    /// Where Esc leads: every mode sits under the project list, except the
+    /// configuration screens, which nest under Configuration.
+    fn parent(&self) -> Option<SelectorMode> {
+        match self {
+            SelectorMode::ProjectSelection => None,
+            SelectorMode::PickingEditor | SelectorMode::EditingPath => Some(SelectorMode::Configuration),
+            SelectorMode::EditingEditor => Some(SelectorMode::PickingEditor),
+            _ => Some(SelectorMode::ProjectSelection),
+        }
+    }
+
+    fn title(&self) -> &'static str {
+        match self {
+            SelectorMode::ProjectSelection => "slop",
+            SelectorMode::TemplateSelection => "New project",
+            SelectorMode::Configuration => "Configuration",
+            SelectorMode::PickingEditor => "Editor",
+            SelectorMode::EditingPath => "Projects path",
+            SelectorMode::EditingEditor => "Command",
+            SelectorMode::ConfirmDelete => "Delete",
+            SelectorMode::ConfirmCollision => "Name taken",
+            SelectorMode::PickingDestination => "Destination",
+            SelectorMode::ForkName => "Fork",
+            SelectorMode::WorktreeBranch => "Worktree",
+            SelectorMode::MovingProject => "Move",
+            SelectorMode::CloneName => "Clone as",
+            SelectorMode::ProjectEditor => "Project editor",
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -155,6 +838,15 @@ struct SelectionResult {
     path: PathBuf,
     template: Option<ProjectTemplate>,
     git_url: Option<String>,
+    /// Subdirectory from a `user/repo:path` clone; only that tree is checked out
+    sparse_path: Option<String>,
+    /// Folder inside the clone to open, from `user/repo/path/to/sub`
+    subdir: Option<String>,
+    /// Branch, tag or commit from a `/tree/<ref>/` or `/blob/<ref>/` link, checked out after cloning
+    git_ref: Option<String>,
+    open: bool,
+    /// Every project to open together when several were marked with Tab
+    workspace: Vec<PathBuf>,
 }
 
 #[derive(Debug, Clone)]
@@ -165,235 +857,298 @@ enum SelectionAction {
 }
 
 impl VibeSelector {
-    fn new(search_term: String, base_path: PathBuf) -> Result<Self> {
+    fn new(search_term: String, roots: Vec<PathBuf>) -> Result<Self> {
         let input_buffer = search_term.replace(' ', "-");
-        
-        // Create base directory if it doesn't exist
-        fs::create_dir_all(&base_path)
-            .with_context(|| format!("Failed to create base directory: {}", base_path.display()))?;
+
+        // New projects land in the first (primary) root
+        let base_path = roots
+            .first()
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("No projects directory configured"))?;
+
+        ensure_roots(&roots)?;
 
         let (term_width, term_height) = size().unwrap_or((80, 24));
+        let (size_sender, sizes_found) = mpsc::channel();
+        let config = load_config(&get_config_file_path().unwrap_or_default()).unwrap_or_default();
+        let last_template = if config.remember_template { load_last_template() } else { None };
 
         Ok(VibeSelector {
             cursor_pos: 0,
             scroll_offset: 0,
             input_buffer,
+            input_cursor_back: 0,
             selected: None,
             term_width,
             term_height,
             all_projects: None,
+            scan: None,
             base_path,
+            roots,
             mode: SelectorMode::ProjectSelection,
             delete_target: None,
+            delete_warnings: Vec::new(),
+            delete_armed: false,
+            delete_permanent: false,
+            fork_source: None,
+            worktree_source: None,
+            move_source: None,
+            clone_pending: None,
+            editor_source: None,
+            name_collision: None,
+            status_message: None,
+            yanked_path: None,
+            try_index: false,
+            marked: Vec::new(),
+            pick_destination: false,
+            pending_destination: None,
+            show_help: false,
+            template_filter: None,
+            show_score: false,
+            last_template,
+            row_format: parse_row_format(&config.row_format),
+            project_sizes: HashMap::new(),
+            size_sender,
+            sizes_found,
+            project_remotes: HashMap::new(),
+            theme: config.theme.effective(),
+            keymap: Keymap::new(&config.keymap),
+            config,
         })
     }
 
+    fn paint(&self, hue: Hue) -> Paint {
+        Paint(self.theme.color(hue))
+    }
+
     fn run(&mut self) -> Result<Option<SelectionResult>> {
-        // Check if we have a TTY
+        // Without a TTY only a query that needs no choosing can go ahead
         if !io::stdin().is_terminal() || !io::stderr().is_terminal() {
-            eprintln!("Error: slop requires an interactive terminal");
-            return Ok(None);
+            let result = self.headless_selection()?;
+            if result.is_none() {
+                say_err!("Error: slop requires an interactive terminal");
+                if !self.input_buffer.is_empty() {
+                    say_err!("Without one, pass the exact name of a single project, or a repo to clone");
+                }
+            }
+            return Ok(result);
         }
 
-        self.setup_terminal()?;
-        
+        let terminal = self.setup_terminal()?;
+
+        // Only the navigator trades a possibly stale list for a fast first paint
+        self.try_index = true;
+        self.pick_destination = true;
         let result = self.main_loop();
         
-        self.restore_terminal()?;
+        // Restore before the error (if any) gets printed
+        terminal.restore()?;
+
+        // Headless fallback for Y when there was no clipboard to copy into
+        if let Some(path) = &self.yanked_path {
+            println!("{}", path.display());
+        }
         
         result
     }
 
-    fn setup_terminal(&mut self) -> Result<()> {
-        enable_raw_mode()?;
+    /// What the query unambiguously asks for: a repo (URL or alias) to clone,
+    /// or a project whose name is exactly the query.
+    fn headless_selection(&mut self) -> Result<Option<SelectionResult>> {
+        if self.input_buffer.is_empty() {
+            return Ok(None);
+        }
+        if self.config.aliases.contains_key(&self.input_buffer) || self.is_github_url(&self.input_buffer) {
+            self.handle_clone_repo(true, false)?;
+            if self.mode == SelectorMode::PickingDestination {
+                return Err(anyhow::anyhow!(
+                    "{} could be cloned into any of {} roots, and picking one needs an interactive terminal",
+                    self.input_buffer,
+                    self.roots.len()
+                ));
+            }
+            return Ok(self.selected.take());
+        }
+
+        // The same name in two roots is a choice, and choices need the navigator
+        self.load_all_projects()?;
+        let exact: Vec<Project> = self.all_projects.as_ref().unwrap().iter().filter(|p| p.name == self.input_buffer).cloned().collect();
+        if let [project] = exact.as_slice() {
+            self.handle_project_selection(project);
+        }
+        Ok(self.selected.take())
+    }
+
+    fn setup_terminal(&mut self) -> Result<TerminalGuard> {
+        // From here on any early return restores the terminal via the guard
+        let guard = TerminalGuard::enter(!self.config.accessible)?;
+        if self.config.accessible {
+            // Stay on the main screen so screen readers can follow the output
+            self.announce("slop: type to search, arrows to move, Enter to select, Esc to exit")?;
+            self.update_terminal_size()?;
+            return Ok(guard);
+        }
         execute!(io::stderr(), EnterAlternateScreen, Hide, Clear(ClearType::All))?;
         self.update_terminal_size()?;
-        Ok(())
+        Ok(guard)
     }
 
     fn update_terminal_size(&mut self) -> Result<()> {
         let (width, height) = size().unwrap_or((80, 24));
-        // Ensure minimum usable size
-        self.term_width = width.max(40);
-        self.term_height = height.max(10);
+        // Keep the real size; the render functions shrink to fit instead
+        self.term_width = width.max(1);
+        self.term_height = height.max(1);
         Ok(())
     }
 
-    fn restore_terminal(&self) -> Result<()> {
-        execute!(
-            io::stderr(),
-            Show,
-            LeaveAlternateScreen,
-            Clear(ClearType::All)
-        )?;
-        disable_raw_mode()?;
-        Ok(())
+    /// Rows available for a list below `chrome` fixed lines, and whether the
+    /// two-line footer still fits. Always leaves room for at least one row.
+    fn list_layout(&self, chrome: usize) -> (usize, bool) {
+        let height = self.term_height as usize;
+        if height > chrome + 2 {
+            (height - chrome - 2, true)
+        } else {
+            (height.saturating_sub(chrome).max(1), false)
+        }
+    }
+
+    /// Truncate to what's left of the line after `used` columns, so it never wraps.
+    fn fit(&self, text: &str, used: usize) -> String {
+        text.chars().take((self.term_width as usize).saturating_sub(used + 1).max(1)).collect()
     }
 
     fn load_all_projects(&mut self) -> Result<()> {
-        if self.all_projects.is_some() {
-            return Ok(());
+        if self.scan.is_some() {
+            return self.poll_scan(true);
         }
-
-        let mut projects = Vec::new();
-        let entries = fs::read_dir(&self.base_path)
-            .with_context(|| format!("Failed to read directory: {}", self.base_path.display()))?;
-
-        for entry in entries {
-            let entry = entry?;
-            let path = entry.path();
-            
-            if path.is_dir() {
-                if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
-                    let metadata = entry.metadata()?;
-                    let (created, last_accessed) = self.get_times(&metadata)?;
-                    
-                    // Check if it's a git repo
-                    let project_type = if path.join(".git").exists() {
-                        ProjectType::GitRepo
-                    } else {
-                        ProjectType::Local
-                    };
-                    
-                    projects.push(Project {
-                        name: name.to_string(),
-                        path: path.clone(),
-                        last_accessed,
-                        created,
-                        score: 0.0,
-                        project_type,
-                    });
-                }
-            }
+        if self.all_projects.is_some() || self.load_index() {
+            return Ok(());
         }
 
-        self.all_projects = Some(projects);
+        self.all_projects = Some(scan_projects(&self.roots, &self.config.scan_options())?);
+        self.save_index();
         Ok(())
     }
 
+    /// Take the project list from the index, once per session. Returns whether
+    /// it's recent enough to skip scanning; a stale list is still loaded.
+    fn load_index(&mut self) -> bool {
+        if !std::mem::take(&mut self.try_index) {
+            return false;
+        }
+        let Some(index) = self.config.index_path().and_then(|path| load_index(&path, &self.roots)) else {
+            return false;
+        };
+        let age = Utc::now().signed_duration_since(index.updated).num_seconds();
+        self.all_projects = Some(index.projects);
+        (0..self.config.index_ttl as i64).contains(&age)
+    }
 
-    fn get_times(&self, metadata: &Metadata) -> Result<(DateTime<Utc>, DateTime<Utc>)> {
-        let created = metadata
-            .created()
-            .or_else(|_| metadata.modified())
-            .unwrap_or(UNIX_EPOCH);
-        let last_accessed = metadata.modified().unwrap_or(UNIX_EPOCH);
-        
-        let created = DateTime::from(created);
-        let last_accessed = DateTime::from(last_accessed);
-        
-        Ok((created, last_accessed))
+    fn save_index(&self) {
+        if let (Some(path), Some(projects)) = (self.config.index_path(), &self.all_projects) {
+            let _ = save_index(&path, &self.roots, projects);
+        }
     }
 
     fn get_projects(&mut self) -> Result<Vec<Project>> {
         self.load_all_projects()?;
-        
-        let mut scored_projects: Vec<Project> = self
-            .all_projects
-            .as_ref()
-            .unwrap()
-            .iter()
-            .map(|project| {
-                let score = self.calculate_score(
-                    &project.name,
-                    &self.input_buffer,
-                    &project.created,
-                    &project.last_accessed,
-                );
-                let mut project = project.clone();
-                project.score = score;
-                project
-            })
-            .collect();
+        Ok(rank_projects(self.all_projects.as_ref().unwrap(), &self.input_buffer, &self.config.pinned))
+    }
 
-        // Filter and sort
-        if self.input_buffer.is_empty() {
-            scored_projects.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
-        } else {
-            scored_projects.retain(|p| p.score > 0.0);
-            scored_projects.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
+    /// Like `get_projects`, but returns whatever a background scan has found so far.
+    fn get_projects_so_far(&mut self) -> Result<Vec<Project>> {
+        if self.all_projects.is_none() && !self.load_index() {
+            let (sender, found) = mpsc::channel();
+            let roots = self.roots.clone();
+            let options = self.config.scan_options();
+            let handle = thread::spawn(move || {
+                scan_projects_with(&roots, &options, |project| {
+                    let _ = sender.send(project);
+                })
+            });
+            // Replacing an older scan drops its receiver, so it stops mattering
+            self.scan = Some(BackgroundScan {
+                found,
+                handle,
+                fresh: self.all_projects.is_some().then(Vec::new),
+            });
+            self.all_projects.get_or_insert_with(Vec::new);
         }
 
-        Ok(scored_projects)
+        self.poll_scan(false)?;
+        Ok(rank_projects(self.all_projects.as_ref().unwrap(), &self.input_buffer, &self.config.pinned))
     }
 
-    fn calculate_score(&self, text: &str, query: &str, created: &DateTime<Utc>, last_accessed: &DateTime<Utc>) -> f64 {
-        let mut score = 0.0;
+    /// Move what the background scan has found into `all_projects`; with `wait`,
+    /// block until it finishes.
+    fn poll_scan(&mut self, wait: bool) -> Result<()> {
+        let Some(scan) = &mut self.scan else {
+            return Ok(());
+        };
+        let projects = match &mut scan.fresh {
+            Some(fresh) => fresh,
+            None => self.all_projects.get_or_insert_with(Vec::new),
+        };
+        loop {
+            let next = if wait {
+                scan.found.recv().map_err(|_| TryRecvError::Disconnected)
+            } else {
+                scan.found.try_recv()
+            };
+            match next {
+                Ok(project) => projects.push(project),
+                Err(TryRecvError::Empty) => return Ok(()),
+                Err(TryRecvError::Disconnected) => break,
+            }
+        }
 
-        // Search query matching
-        if !query.is_empty() {
-            let text_lower = text.to_lowercase();
-            let query_lower = query.to_lowercase();
-            let query_chars: Vec<char> = query_lower.chars().collect();
-            
-            let mut last_pos = -1i32;
-            let mut query_idx = 0;
+        let scan = self.scan.take().unwrap();
+        scan.handle.join().map_err(|_| anyhow::anyhow!("Project scan panicked"))??;
+        if let Some(fresh) = scan.fresh {
+            self.all_projects = Some(fresh);
+        }
+        self.save_index();
+        Ok(())
+    }
 
-            for (pos, ch) in text_lower.chars().enumerate() {
-                if query_idx >= query_chars.len() {
-                    break;
-                }
-                if ch != query_chars[query_idx] {
-                    continue;
+    // Arms keep their inner `if` so a refused key does not fall through to the text-input arms
+    #[allow(clippy::collapsible_match)]
+    fn main_loop(&mut self) -> Result<Option<SelectionResult>> {
+        loop {
+            if self.show_help {
+                self.render_help()?;
+                if self.read_key(false)?.is_some() {
+                    self.show_help = false;
                 }
+                continue;
+            }
 
-                // Base point + word boundary bonus
-                score += 1.0;
-                if pos == 0 || !text_lower.chars().nth(pos.saturating_sub(1)).unwrap_or('a').is_alphanumeric() {
-                    score += 1.0;
-                }
-
-                // Proximity bonus
-                if last_pos >= 0 {
-                    let gap = pos as i32 - last_pos - 1;
-                    score += 1.0 / (gap as f64 + 1.0).sqrt();
-                }
-
-                last_pos = pos as i32;
-                query_idx += 1;
-            }
-
-            // Return 0 if not all query chars matched
-            if query_idx < query_chars.len() {
-                return 0.0;
-            }
-
-            // Density bonus
-            if last_pos >= 0 {
-                score *= query_chars.len() as f64 / (last_pos as f64 + 1.0);
-            }
-
-            // Length penalty
-            score *= 10.0 / (text.len() as f64 + 10.0);
-        }
-
-        // Time-based scoring
-        let now = Utc::now();
-        
-        // Creation time bonus
-        let days_old = (now - *created).num_seconds() as f64 / 86400.0;
-        score += 2.0 / (days_old + 1.0).sqrt();
-
-        // Access time bonus (most important)
-        let hours_since_access = (now - *last_accessed).num_seconds() as f64 / 3600.0;
-        score += 5.0 / (hours_since_access + 1.0).sqrt();
-
-        score
-    }
-
-    fn main_loop(&mut self) -> Result<Option<SelectionResult>> {
-        loop {
             match self.mode {
                 SelectorMode::ProjectSelection => {
-                    let projects = self.get_projects()?;
+                    // Screen readers get one complete list instead of a stream of partial ones
+                    let projects = if self.config.accessible {
+                        self.get_projects()?
+                    } else {
+                        self.get_projects_so_far()?
+                    };
                     
                     let create_new_text = if self.input_buffer.is_empty() {
-                        "✨ Create new project (select template)".to_string()
+                        match &self.last_template {
+                            Some(template) => format!("✨ Create new project (select template, last: {})", template.display_name()),
+                            None => "✨ Create new project (select template)".to_string(),
+                        }
+                    } else if let Some(url) = self.config.aliases.get(&self.input_buffer) {
+                        let repo_name = self.extract_repo_name(&self.normalize_github_url(url));
+                        format!("🚀 Clone {} (alias)", repo_name)
                     } else if self.is_github_url(&self.input_buffer) {
                         let repo_name = self.extract_repo_name(&self.normalize_github_url(&self.input_buffer));
                         format!("🚀 Clone {}", repo_name)
                     } else {
-                        format!("✨ Create {} (blank template)", self.input_buffer)
+                        format!(
+                            "✨ Create {} ({} template)",
+                            self.input_buffer,
+                            self.config.default_template.display_name().to_lowercase()
+                        )
                     };
 
                     let total_items = projects.len() + 2; // +1 for create new, +1 for config
@@ -405,32 +1160,41 @@ impl VibeSelector {
 
                     // Update terminal size before handling input
                     self.update_terminal_size()?;
-                    
-                    if let Event::Key(key) = event::read()? {
+
+                    // While scanning, redraw as results come in instead of blocking on a key
+                    if (self.scan.is_some() || self.sizes_pending()) && !event::poll(Duration::from_millis(100))? {
+                        continue;
+                    }
+
+                    if let Some(key) = self.read_key(true)? {
+                        self.status_message = None;
+                        let action = self.keymap.action(&key, !self.input_buffer.is_empty());
                         match key {
-                            KeyEvent { code: KeyCode::Up, .. } | KeyEvent { code: KeyCode::Char('p'), modifiers: KeyModifiers::CONTROL, .. } => {
+                            _ if action == Some(KeyAction::Up) => {
                                 if self.cursor_pos > 0 {
                                     self.cursor_pos -= 1;
                                 }
                             }
-                            KeyEvent { code: KeyCode::Down, .. } | KeyEvent { code: KeyCode::Char('n'), modifiers: KeyModifiers::CONTROL, .. } => {
+                            _ if action == Some(KeyAction::Down) => {
                                 if self.cursor_pos < total_items.saturating_sub(1) {
                                     self.cursor_pos += 1;
                                 }
                             }
-                            KeyEvent { code: KeyCode::Enter, .. } => {
+                            KeyEvent { modifiers, .. } if action == Some(KeyAction::Select) => {
                                 if self.cursor_pos < projects.len() {
                                     // Selected existing project
                                     self.handle_project_selection(&projects[self.cursor_pos]);
                                 } else if self.cursor_pos == projects.len() {
-                                    // Selected "Create new"
-                                    if self.is_github_url(&self.input_buffer) {
-                                        self.handle_clone_repo()?;
-                                    } else if !self.input_buffer.is_empty() {
+                                    // Selected "Create new" - aliases win over literal names
+                                    if self.config.aliases.contains_key(&self.input_buffer) || self.is_github_url(&self.input_buffer) {
+                                        // Shift/Alt+Enter clones without opening the editor
+                                        let clone_only = modifiers.intersects(KeyModifiers::SHIFT | KeyModifiers::ALT);
+                                        self.handle_clone_repo(!clone_only, true)?;
+                                    } else if !self.input_buffer.is_empty() && !modifiers.contains(KeyModifiers::ALT) {
                                         // If name is already typed, create with default template
-                                        self.handle_template_selection(ProjectTemplate::Blank)?;
+                                        self.handle_template_selection(self.config.default_template.clone())?;
                                     } else {
-                                        // No name typed, go to template selection
+                                        // No name typed (or Alt+Enter), go to template selection
                                         self.handle_create_new()?;
                                     }
                                 } else {
@@ -443,37 +1207,181 @@ impl VibeSelector {
                                 }
                             }
                             KeyEvent { code: KeyCode::Backspace, .. } => {
-                                if !self.input_buffer.is_empty() {
-                                    self.input_buffer.pop();
+                                if self.input_cursor_index() > 0 {
+                                    let end = self.input_cursor_index();
+                                    let start = self.input_buffer[..end].char_indices().next_back().map_or(0, |(i, _)| i);
+                                    self.input_buffer.replace_range(start..end, "");
                                     self.cursor_pos = 0;
                                 }
                             }
-                            KeyEvent { code: KeyCode::Char('c'), modifiers: KeyModifiers::CONTROL, .. } | 
-                            KeyEvent { code: KeyCode::Esc, .. } => {
-                                if !self.input_buffer.is_empty() {
+                            KeyEvent { code: KeyCode::Left, .. } => {
+                                self.input_cursor_back = (self.input_cursor_back + 1).min(self.input_buffer.chars().count());
+                            }
+                            KeyEvent { code: KeyCode::Right, .. } => {
+                                self.input_cursor_back = self.input_cursor_back.saturating_sub(1);
+                            }
+                            KeyEvent { code: KeyCode::Home, .. } => {
+                                self.input_cursor_back = self.input_buffer.chars().count();
+                            }
+                            KeyEvent { code: KeyCode::End, .. } => {
+                                self.input_cursor_back = 0;
+                            }
+                            _ if action == Some(KeyAction::Clear) => {
+                                self.go_back();
+                            }
+                            _ if action == Some(KeyAction::Quit) => {
+                                if !self.go_back() {
+                                    self.selected = None;
+                                    break;
+                                }
+                            }
+                            _ if action == Some(KeyAction::Config) => {
+                                self.mode = SelectorMode::Configuration;
+                                self.cursor_pos = 0;
+                            }
+                            _ if action == Some(KeyAction::Editor) => {
+                                self.cycle_editor();
+                            }
+                            KeyEvent { code: KeyCode::Char('s'), modifiers: KeyModifiers::CONTROL, .. } => {
+                                self.keep_editor();
+                            }
+                            _ if action == Some(KeyAction::Mark) => {
+                                if let Some(project) = projects.get(self.cursor_pos) {
+                                    if let Some(idx) = self.marked.iter().position(|p| p == &project.path) {
+                                        self.marked.remove(idx);
+                                    } else {
+                                        self.marked.push(project.path.clone());
+                                    }
+                                    if !self.marked.is_empty() {
+                                        self.status_message = Some(format!("{} marked - Enter opens them together", self.marked.len()));
+                                    }
+                                    self.cursor_pos = (self.cursor_pos + 1).min(projects.len().saturating_sub(1));
+                                }
+                            }
+                            KeyEvent { code: KeyCode::Char('y'), modifiers: KeyModifiers::NONE, .. }
+                                if !is_text_key(&key, !self.input_buffer.is_empty()) && self.cursor_pos < projects.len() =>
+                            {
+                                let path = projects[self.cursor_pos].path.clone();
+                                if set_clipboard_content(&path.display().to_string()).is_ok() {
+                                    self.status_message = Some(format!("📋 Copied {}", path.display()));
+                                } else {
+                                    self.status_message = Some("No clipboard available - path will be printed on exit".to_string());
+                                    self.yanked_path = Some(path);
+                                }
+                            }
+                            KeyEvent { code: KeyCode::Char('o'), modifiers: KeyModifiers::NONE, .. }
+                                if !is_text_key(&key, !self.input_buffer.is_empty()) && self.cursor_pos < projects.len() =>
+                            {
+                                let project = &projects[self.cursor_pos];
+                                self.status_message = Some(match project_web_url(project).and_then(|url| {
+                                    open_in_browser(&url)?;
+                                    Ok(url)
+                                }) {
+                                    Ok(url) => format!("🌐 Opened {}", url),
+                                    Err(e) => e.to_string(),
+                                });
+                            }
+                            KeyEvent { code: KeyCode::Char('f'), modifiers: KeyModifiers::NONE, .. }
+                                if !is_text_key(&key, !self.input_buffer.is_empty()) && self.cursor_pos < projects.len() =>
+                            {
+                                let source = projects[self.cursor_pos].clone();
+                                self.input_buffer = format!("{}-fork", source.name);
+                                self.fork_source = Some(source);
+                                self.status_message = None;
+                                self.mode = SelectorMode::ForkName;
+                            }
+                            KeyEvent { code: KeyCode::Char('w'), modifiers: KeyModifiers::NONE, .. }
+                                if !is_text_key(&key, !self.input_buffer.is_empty()) && self.cursor_pos < projects.len() =>
+                            {
+                                let source = projects[self.cursor_pos].clone();
+                                if matches!(source.project_type, ProjectType::GitRepo) {
                                     self.input_buffer.clear();
-                                    self.cursor_pos = 0;
+                                    self.input_cursor_back = 0;
+                                    self.worktree_source = Some(source);
+                                    self.status_message = None;
+                                    self.mode = SelectorMode::WorktreeBranch;
                                 } else {
-                                    self.selected = None;
+                                    self.status_message = Some(format!("{} is not a git repository", source.name));
+                                }
+                            }
+                            KeyEvent { code: KeyCode::Char('m'), modifiers: KeyModifiers::NONE, .. }
+                                if !is_text_key(&key, !self.input_buffer.is_empty()) && self.cursor_pos < projects.len() =>
+                            {
+                                let source = projects[self.cursor_pos].clone();
+                                if self.roots.len() < 2 {
+                                    self.status_message = Some("Only one project root - list more in projects_paths to move between them".to_string());
+                                } else {
+                                    // Start on the first root it isn't already in
+                                    let row = self.cursor_pos;
+                                    self.cursor_pos = self.roots.iter().position(|root| *root != source.root).unwrap_or(0);
+                                    self.move_source = Some((source, row));
+                                    self.status_message = None;
+                                    self.mode = SelectorMode::MovingProject;
+                                }
+                            }
+                            KeyEvent { code: KeyCode::Char('e'), modifiers: KeyModifiers::NONE, .. }
+                                if !is_text_key(&key, !self.input_buffer.is_empty()) && self.cursor_pos < projects.len() =>
+                            {
+                                let source = projects[self.cursor_pos].clone();
+                                self.input_buffer = load_project_editor(&source.path).unwrap_or_default();
+                                self.input_cursor_back = 0;
+                                self.editor_source = Some(source);
+                                self.status_message = None;
+                                self.mode = SelectorMode::ProjectEditor;
+                            }
+                            KeyEvent { code: KeyCode::Char(digit @ '1'..='9'), modifiers: KeyModifiers::ALT, .. } => {
+                                // Alt+N opens the Nth visible project
+                                let idx = self.scroll_offset + (digit as usize - '1' as usize);
+                                if idx < projects.len() {
+                                    self.cursor_pos = idx;
+                                    self.handle_project_selection(&projects[idx]);
                                     break;
                                 }
                             }
-                            KeyEvent { code: KeyCode::Delete, .. } | KeyEvent { code: KeyCode::Char('d'), .. } => {
+                            KeyEvent { code: KeyCode::F(12), .. } => {
+                                self.show_score = !self.show_score;
+                            }
+                            KeyEvent { code: KeyCode::Char('r'), modifiers: KeyModifiers::CONTROL, .. } | KeyEvent { code: KeyCode::F(5), .. } => {
+                                // Pick up projects created or cloned outside slop
+                                self.scan = None;
+                                self.all_projects = None;
+                                self.project_sizes.clear();
+                                self.project_remotes.clear();
+                                self.cursor_pos = 0;
+                                self.scroll_offset = 0;
+                                self.status_message = Some("Refreshed".to_string());
+                            }
+                            KeyEvent { code: KeyCode::Char('*'), .. } => {
+                                if self.cursor_pos < projects.len() {
+                                    let name = projects[self.cursor_pos].name.clone();
+                                    match self.toggle_pin(&name) {
+                                        Ok(true) => self.status_message = Some(format!("★ Pinned {}", name)),
+                                        Ok(false) => self.status_message = Some(format!("Unpinned {}", name)),
+                                        Err(e) => self.status_message = Some(e.to_string()),
+                                    }
+                                }
+                            }
+                            _ if action == Some(KeyAction::Delete) => {
                                 if self.cursor_pos < projects.len() {
-                                    self.delete_target = Some(self.cursor_pos);
+                                    self.delete_target = Some(projects[self.cursor_pos].path.clone());
+                                    self.delete_warnings = unsaved_work(&projects[self.cursor_pos]);
+                                    self.delete_armed = false;
+                                    self.delete_permanent = false;
                                     self.mode = SelectorMode::ConfirmDelete;
                                 }
                             }
                             KeyEvent { code: KeyCode::Char('v'), modifiers: KeyModifiers::CONTROL, .. } => {
                                 // Handle paste (Cmd+V on macOS, Ctrl+V on others)
                                 if let Ok(clipboard_content) = get_clipboard_content() {
-                                    self.input_buffer.push_str(&clipboard_content);
+                                    let at = self.input_cursor_index();
+                                    self.input_buffer.insert_str(at, &sanitize_paste(&clipboard_content));
                                     self.cursor_pos = 0;
                                 }
                             }
                             KeyEvent { code: KeyCode::Char(ch), .. } => {
-                                if ch.is_alphanumeric() || ch == '-' || ch == '_' || ch == '.' || ch == ' ' || ch == '/' || ch == ':' {
-                                    self.input_buffer.push(ch);
+                                if is_query_char(ch) {
+                                    let at = self.input_cursor_index();
+                                    self.input_buffer.insert(at, ch);
                                     self.cursor_pos = 0;
                                 }
                             }
@@ -482,12 +1390,36 @@ impl VibeSelector {
                     }
                 }
                 SelectorMode::TemplateSelection => {
-                    let templates = ProjectTemplate::get_all();
+                    let templates = self.filtered_templates();
                     self.cursor_pos = self.cursor_pos.min(templates.len().saturating_sub(1));
                     
                     self.render_template_selection(&templates)?;
 
-                    if let Event::Key(key) = event::read()? {
+                    if let Some(key) = self.read_key(true)? {
+                        // While filtering, typing narrows the list instead of editing the name
+                        if let Some(filter) = self.template_filter.as_mut() {
+                            match key {
+                                KeyEvent { code: KeyCode::Esc, .. } => {
+                                    self.template_filter = None;
+                                    continue;
+                                }
+                                KeyEvent { code: KeyCode::Backspace, .. } => {
+                                    if filter.pop().is_none() {
+                                        self.template_filter = None;
+                                    }
+                                    self.cursor_pos = 0;
+                                    continue;
+                                }
+                                KeyEvent { code: KeyCode::Char(ch), modifiers, .. }
+                                    if !modifiers.contains(KeyModifiers::CONTROL) && (ch.is_alphanumeric() || ch == ' ') =>
+                                {
+                                    filter.push(ch);
+                                    self.cursor_pos = 0;
+                                    continue;
+                                }
+                                _ => {}
+                            }
+                        }
                         match key {
                             KeyEvent { code: KeyCode::Up, .. } | KeyEvent { code: KeyCode::Char('p'), modifiers: KeyModifiers::CONTROL, .. } => {
                                 if self.cursor_pos > 0 {
@@ -499,15 +1431,22 @@ impl VibeSelector {
                                     self.cursor_pos += 1;
                                 }
                             }
-                            KeyEvent { code: KeyCode::Enter, .. } => {
+                            KeyEvent { code: KeyCode::Char('/'), modifiers: KeyModifiers::NONE, .. } => {
+                                self.template_filter = Some(String::new());
+                            }
+                            KeyEvent { code: KeyCode::Enter, .. } if !templates.is_empty() => {
                                 let template = templates[self.cursor_pos].clone();
+                                if self.config.remember_template {
+                                    let _ = save_last_template(&template);
+                                }
                                 self.handle_template_selection(template)?;
-                                break;
+                                if self.selected.is_some() {
+                                    break;
+                                }
                             }
                             KeyEvent { code: KeyCode::Char('c'), modifiers: KeyModifiers::CONTROL, .. } | 
                             KeyEvent { code: KeyCode::Esc, .. } => {
-                                self.mode = SelectorMode::ProjectSelection;
-                                self.cursor_pos = 0;
+                                self.go_back();
                             }
                             KeyEvent { code: KeyCode::Backspace, .. } => {
                                 if !self.input_buffer.is_empty() {
@@ -517,7 +1456,7 @@ impl VibeSelector {
                             KeyEvent { code: KeyCode::Char('v'), modifiers: KeyModifiers::CONTROL, .. } => {
                                 // Handle paste (Cmd+V on macOS, Ctrl+V on others)
                                 if let Ok(clipboard_content) = get_clipboard_content() {
-                                    self.input_buffer.push_str(&clipboard_content);
+                                    self.input_buffer.push_str(&sanitize_paste(&clipboard_content));
                                 }
                             }
                             KeyEvent { code: KeyCode::Char(ch), .. } => {
@@ -532,7 +1471,7 @@ impl VibeSelector {
                 SelectorMode::Configuration => {
                     self.render_configuration_interface()?;
 
-                    if let Event::Key(key) = event::read()? {
+                    if let Some(key) = self.read_key(false)? {
                         match key {
                             KeyEvent { code: KeyCode::Up, .. } | KeyEvent { code: KeyCode::Char('p'), modifiers: KeyModifiers::CONTROL, .. } => {
                                 if self.cursor_pos > 0 {
@@ -552,29 +1491,36 @@ impl VibeSelector {
                                         self.input_buffer = config.projects_path.display().to_string();
                                     },
                                     1 => {
-                                        self.mode = SelectorMode::EditingEditor;
+                                        self.mode = SelectorMode::PickingEditor;
                                         let config = load_config(&get_config_file_path()?).unwrap_or_default();
-                                        self.input_buffer = config.default_editor.clone();
+                                        let editors = installed_editors();
+                                        // Start on the configured editor, or on "Other command" if it isn't listed
+                                        self.cursor_pos = editors
+                                            .iter()
+                                            .position(|e| *e == editor_program(&config.default_editor))
+                                            .unwrap_or(editors.len());
                                     },
                                     _ => {
-                                        self.mode = SelectorMode::ProjectSelection;
-                                        self.cursor_pos = 0;
+                                        self.go_back();
                                     }
                                 }
                             }
                             KeyEvent { code: KeyCode::Char('c'), modifiers: KeyModifiers::CONTROL, .. } | 
                             KeyEvent { code: KeyCode::Esc, .. } => {
-                                self.mode = SelectorMode::ProjectSelection;
-                                self.cursor_pos = 0;
+                                self.go_back();
                             }
                             _ => {}
                         }
                     }
                 }
                 SelectorMode::EditingPath => {
-                    self.render_inline_edit("📁 Projects Path", &self.input_buffer.clone())?;
+                    self.render_inline_edit(
+                        "📁 Projects Path",
+                        &self.input_buffer.clone(),
+                        Some(validate_projects_path(&self.input_buffer)),
+                    )?;
                     
-                    if let Event::Key(key) = event::read()? {
+                    if let Some(key) = self.read_key(false)? {
                         match key {
                             KeyEvent { code: KeyCode::Enter, .. } => {
                                 let mut config = load_config(&get_config_file_path()?).unwrap_or_default();
@@ -583,9 +1529,9 @@ impl VibeSelector {
                                 self.mode = SelectorMode::Configuration;
                                 self.input_buffer.clear();
                             }
+                            KeyEvent { code: KeyCode::Char('c'), modifiers: KeyModifiers::CONTROL, .. } |
                             KeyEvent { code: KeyCode::Esc, .. } => {
-                                self.mode = SelectorMode::Configuration;
-                                self.input_buffer.clear();
+                                self.go_back();
                             }
                             KeyEvent { code: KeyCode::Backspace, .. } => {
                                 self.input_buffer.pop();
@@ -597,21 +1543,249 @@ impl VibeSelector {
                         }
                     }
                 }
+                SelectorMode::PickingEditor => {
+                    let editors = installed_editors();
+                    let current = load_config(&get_config_file_path()?).unwrap_or_default().default_editor;
+                    self.cursor_pos = self.cursor_pos.min(editors.len());
+
+                    self.render_editor_picker(&editors, &current)?;
+
+                    if let Some(key) = self.read_key(true)? {
+                        match key {
+                            KeyEvent { code: KeyCode::Up, .. } | KeyEvent { code: KeyCode::Char('p'), modifiers: KeyModifiers::CONTROL, .. } => {
+                                if self.cursor_pos > 0 {
+                                    self.cursor_pos -= 1;
+                                }
+                            }
+                            KeyEvent { code: KeyCode::Down, .. } | KeyEvent { code: KeyCode::Char('n'), modifiers: KeyModifiers::CONTROL, .. } => {
+                                if self.cursor_pos < editors.len() { // last row: other command
+                                    self.cursor_pos += 1;
+                                }
+                            }
+                            KeyEvent { code: KeyCode::Enter, .. } => {
+                                if let Some(editor) = editors.get(self.cursor_pos) {
+                                    let mut config = load_config(&get_config_file_path()?).unwrap_or_default();
+                                    config.default_editor = editor.to_string();
+                                    save_config(&config)?;
+                                    self.mode = SelectorMode::Configuration;
+                                    self.cursor_pos = 1;
+                                } else {
+                                    self.mode = SelectorMode::EditingEditor;
+                                    self.input_buffer = current;
+                                }
+                            }
+                            KeyEvent { code: KeyCode::Char('c'), modifiers: KeyModifiers::CONTROL, .. } |
+                            KeyEvent { code: KeyCode::Esc, .. } => {
+                                self.go_back();
+                            }
+                            _ => {}
+                        }
+                    }
+                }
                 SelectorMode::EditingEditor => {
-                    self.render_inline_edit("✏️  Editor Command", &self.input_buffer.clone())?;
+                    self.render_inline_edit(
+                        "✏️  Editor Command",
+                        &self.input_buffer.clone(),
+                        Some(validate_editor_command(&self.input_buffer)),
+                    )?;
                     
-                    if let Event::Key(key) = event::read()? {
+                    if let Some(key) = self.read_key(true)? {
                         match key {
                             KeyEvent { code: KeyCode::Enter, .. } => {
                                 let mut config = load_config(&get_config_file_path()?).unwrap_or_default();
                                 config.default_editor = self.input_buffer.clone();
                                 save_config(&config)?;
                                 self.mode = SelectorMode::Configuration;
+                                self.cursor_pos = 1;
                                 self.input_buffer.clear();
                             }
+                            KeyEvent { code: KeyCode::Char('c'), modifiers: KeyModifiers::CONTROL, .. } |
                             KeyEvent { code: KeyCode::Esc, .. } => {
-                                self.mode = SelectorMode::Configuration;
-                                self.input_buffer.clear();
+                                self.go_back();
+                            }
+                            KeyEvent { code: KeyCode::Backspace, .. } => {
+                                self.input_buffer.pop();
+                            }
+                            KeyEvent { code: KeyCode::Char(c), .. } => {
+                                self.input_buffer.push(c);
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                SelectorMode::ForkName => {
+                    let source_name = self.fork_source.as_ref().map(|p| p.name.clone()).unwrap_or_default();
+                    self.render_inline_edit(
+                        &format!("🍴 New name for {}", source_name),
+                        &self.input_buffer.clone(),
+                        None,
+                    )?;
+
+                    if let Some(key) = self.read_key(true)? {
+                        match key {
+                            KeyEvent { code: KeyCode::Enter, .. } => {
+                                if let Some(source) = self.fork_source.clone() {
+                                    match self.fork_project(&source, &self.input_buffer.clone()) {
+                                        Ok(()) => {
+                                            self.all_projects = None; // Force reload
+                                            self.fork_source = None;
+                                            self.status_message = None;
+                                            self.input_buffer.clear();
+                                            self.mode = SelectorMode::ProjectSelection;
+                                            self.cursor_pos = 0;
+                                        }
+                                        Err(e) => self.status_message = Some(e.to_string()),
+                                    }
+                                }
+                            }
+                            KeyEvent { code: KeyCode::Char('c'), modifiers: KeyModifiers::CONTROL, .. } |
+                            KeyEvent { code: KeyCode::Esc, .. } => {
+                                self.go_back();
+                            }
+                            KeyEvent { code: KeyCode::Backspace, .. } => {
+                                self.input_buffer.pop();
+                            }
+                            KeyEvent { code: KeyCode::Char(c), .. } => {
+                                self.input_buffer.push(c);
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                SelectorMode::ProjectEditor => {
+                    let source_name = self.editor_source.as_ref().map(|p| p.name.clone()).unwrap_or_default();
+                    let hint = self
+                        .input_buffer
+                        .trim()
+                        .is_empty()
+                        .then(|| (Hue::Meta, format!("empty = {} (default_editor)", self.config.default_editor)));
+                    self.render_inline_edit(&format!("✏️  Always open {} in", source_name), &self.input_buffer.clone(), hint)?;
+
+                    if let Some(key) = self.read_key(true)? {
+                        match key {
+                            KeyEvent { code: KeyCode::Enter, .. } => {
+                                if let Some(source) = self.editor_source.clone() {
+                                    let editor = self.input_buffer.trim().to_string();
+                                    match save_project_editor(&source.path, Some(editor.as_str()).filter(|e| !e.is_empty())) {
+                                        Ok(()) => {
+                                            self.go_back();
+                                            self.status_message = Some(if editor.is_empty() {
+                                                format!("{} opens in {} again", source.name, self.config.default_editor)
+                                            } else {
+                                                format!("{} now always opens in {}", source.name, editor)
+                                            });
+                                        }
+                                        Err(e) => self.status_message = Some(format!("Couldn't save: {}", e)),
+                                    }
+                                }
+                            }
+                            KeyEvent { code: KeyCode::Char('c'), modifiers: KeyModifiers::CONTROL, .. } |
+                            KeyEvent { code: KeyCode::Esc, .. } => {
+                                self.go_back();
+                            }
+                            KeyEvent { code: KeyCode::Backspace, .. } => {
+                                self.input_buffer.pop();
+                            }
+                            KeyEvent { code: KeyCode::Char(c), .. } => {
+                                self.input_buffer.push(c);
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                SelectorMode::CloneName => {
+                    let Some((pending, _)) = self.clone_pending.clone() else {
+                        self.mode = SelectorMode::ProjectSelection;
+                        continue;
+                    };
+                    let name = self.input_buffer.trim().to_string();
+                    let tidy = tidy_project_name(&name);
+                    let hint = if self.base_path.join(&name).exists() {
+                        Some((Hue::Warning, format!("{} already exists", name)))
+                    } else if !tidy.is_empty() && tidy != name {
+                        Some((Hue::Meta, format!("Tab → {}", tidy)))
+                    } else {
+                        None
+                    };
+                    let url = pending.git_url.clone().unwrap_or_default();
+                    self.render_inline_edit(&format!("📦 Clone {} as", url), &self.input_buffer.clone(), hint)?;
+
+                    if let Some(key) = self.read_key(true)? {
+                        match key {
+                            KeyEvent { code: KeyCode::Enter, .. } => {
+                                if let Err(e) = validate_project_name(&name) {
+                                    self.status_message = Some(e.to_string());
+                                } else if self.base_path.join(&name).exists() {
+                                    self.status_message = Some(format!("'{}' already exists", name));
+                                } else {
+                                    self.clone_pending = None;
+                                    self.input_buffer.clear();
+                                    self.status_message = None;
+                                    self.mode = SelectorMode::ProjectSelection;
+                                    self.finish_selection(SelectionResult { path: self.base_path.join(&name), ..pending });
+                                    if self.selected.is_some() {
+                                        break;
+                                    }
+                                }
+                            }
+                            KeyEvent { code: KeyCode::Tab, .. } if !tidy.is_empty() => {
+                                self.input_buffer = tidy;
+                            }
+                            KeyEvent { code: KeyCode::Char('c'), modifiers: KeyModifiers::CONTROL, .. } |
+                            KeyEvent { code: KeyCode::Esc, .. } => {
+                                self.go_back();
+                            }
+                            KeyEvent { code: KeyCode::Backspace, .. } => {
+                                self.input_buffer.pop();
+                            }
+                            KeyEvent { code: KeyCode::Char(c), .. } => {
+                                self.input_buffer.push(c);
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                SelectorMode::WorktreeBranch => {
+                    let source_name = self.worktree_source.as_ref().map(|p| p.name.clone()).unwrap_or_default();
+                    let hint = self
+                        .worktree_source
+                        .as_ref()
+                        .filter(|_| !self.input_buffer.trim().is_empty())
+                        .map(|source| (Hue::Meta, format!("→ {}", worktree_path(&source.path, &self.input_buffer).display())));
+                    self.render_inline_edit(
+                        &format!("🌿 Branch of {}", source_name),
+                        &self.input_buffer.clone(),
+                        hint,
+                    )?;
+
+                    if let Some(key) = self.read_key(true)? {
+                        match key {
+                            KeyEvent { code: KeyCode::Enter, .. } => {
+                                if let Some(source) = self.worktree_source.clone() {
+                                    match add_worktree(&source.path, self.input_buffer.trim()) {
+                                        Ok(path) => {
+                                            self.worktree_source = None;
+                                            self.input_buffer.clear();
+                                            self.selected = Some(SelectionResult {
+                                                action: SelectionAction::OpenExisting,
+                                                path,
+                                                template: None,
+                                                git_url: None,
+                                                sparse_path: None,
+                                                subdir: None,
+                                                git_ref: None,
+                                                open: true,
+                                                workspace: Vec::new(),
+                                            });
+                                            break;
+                                        }
+                                        Err(e) => self.status_message = Some(e.to_string()),
+                                    }
+                                }
+                            }
+                            KeyEvent { code: KeyCode::Char('c'), modifiers: KeyModifiers::CONTROL, .. } |
+                            KeyEvent { code: KeyCode::Esc, .. } => {
+                                self.go_back();
                             }
                             KeyEvent { code: KeyCode::Backspace, .. } => {
                                 self.input_buffer.pop();
@@ -623,28 +1797,165 @@ impl VibeSelector {
                         }
                     }
                 }
+                SelectorMode::ConfirmCollision => {
+                    let Some(existing) = self.name_collision.clone() else {
+                        self.mode = SelectorMode::ProjectSelection;
+                        continue;
+                    };
+                    let suggested = suffixed_project_path(&existing.path);
+                    self.render_name_collision(&existing.path, &suggested)?;
+
+                    if let Some(key) = self.read_key(false)? {
+                        match key.code {
+                            KeyCode::Enter | KeyCode::Char('o') | KeyCode::Char('O') => {
+                                self.selected = Some(SelectionResult {
+                                    action: SelectionAction::OpenExisting,
+                                    template: None,
+                                    ..existing
+                                });
+                            }
+                            KeyCode::Char('n') | KeyCode::Char('N') => {
+                                self.selected = Some(SelectionResult { path: suggested, ..existing });
+                            }
+                            _ => {
+                                self.go_back();
+                            }
+                        }
+                        self.name_collision = None;
+                        if self.selected.is_some() {
+                            break;
+                        }
+                    }
+                }
+                SelectorMode::PickingDestination => {
+                    let Some(pending) = self.pending_destination.clone() else {
+                        self.mode = SelectorMode::ProjectSelection;
+                        continue;
+                    };
+                    self.cursor_pos = self.cursor_pos.min(self.roots.len() - 1);
+                    let name = pending.path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+                    let header = match pending.action {
+                        SelectionAction::CloneRepo => format!("{} · clone {} into…", self.breadcrumb(), name),
+                        _ => format!("{} · create {} in…", self.breadcrumb(), name),
+                    };
+                    self.render_destination_picker(&header)?;
+
+                    if let Some(key) = self.read_key(false)? {
+                        match key {
+                            KeyEvent { code: KeyCode::Up, .. } | KeyEvent { code: KeyCode::Char('p'), modifiers: KeyModifiers::CONTROL, .. } => {
+                                self.cursor_pos = self.cursor_pos.saturating_sub(1);
+                            }
+                            KeyEvent { code: KeyCode::Down, .. } | KeyEvent { code: KeyCode::Char('n'), modifiers: KeyModifiers::CONTROL, .. } => {
+                                self.cursor_pos = (self.cursor_pos + 1).min(self.roots.len() - 1);
+                            }
+                            KeyEvent { code: KeyCode::Enter, .. } => {
+                                let root = self.roots[self.cursor_pos].clone();
+                                let _ = save_last_destination(&pending.action, &root);
+                                let name = pending.path.file_name().map(|n| n.to_os_string()).unwrap_or_default();
+                                self.pending_destination = None;
+                                self.place_selection(SelectionResult { path: root.join(name), ..pending });
+                                if self.selected.is_some() {
+                                    break;
+                                }
+                            }
+                            KeyEvent { code: KeyCode::Char('c'), modifiers: KeyModifiers::CONTROL, .. } |
+                            KeyEvent { code: KeyCode::Esc, .. } => {
+                                self.go_back();
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                SelectorMode::MovingProject => {
+                    let Some((source, _)) = self.move_source.clone() else {
+                        self.mode = SelectorMode::ProjectSelection;
+                        continue;
+                    };
+                    self.cursor_pos = self.cursor_pos.min(self.roots.len() - 1);
+                    self.render_destination_picker(&format!("{} · move {} to…", self.breadcrumb(), source.name))?;
+
+                    if let Some(key) = self.read_key(false)? {
+                        match key {
+                            KeyEvent { code: KeyCode::Up, .. } | KeyEvent { code: KeyCode::Char('p'), modifiers: KeyModifiers::CONTROL, .. } => {
+                                self.cursor_pos = self.cursor_pos.saturating_sub(1);
+                            }
+                            KeyEvent { code: KeyCode::Down, .. } | KeyEvent { code: KeyCode::Char('n'), modifiers: KeyModifiers::CONTROL, .. } => {
+                                self.cursor_pos = (self.cursor_pos + 1).min(self.roots.len() - 1);
+                            }
+                            KeyEvent { code: KeyCode::Enter, .. } => {
+                                let root = self.roots[self.cursor_pos].clone();
+                                let moved = if root == source.root {
+                                    Err(anyhow::anyhow!("already in {}", root.display()))
+                                } else {
+                                    self.move_to_root(&source, &root)
+                                };
+                                self.go_back();
+                                self.status_message = Some(match moved {
+                                    Ok(path) => {
+                                        // Keep the moved project under the cursor
+                                        if let Some(row) = self.get_projects()?.iter().position(|p| p.path == path) {
+                                            self.cursor_pos = row;
+                                        }
+                                        format!("Moved {} to {}", source.name, root.display())
+                                    }
+                                    Err(e) => format!("Couldn't move {}: {}", source.name, e),
+                                });
+                            }
+                            KeyEvent { code: KeyCode::Char('c'), modifiers: KeyModifiers::CONTROL, .. } |
+                            KeyEvent { code: KeyCode::Esc, .. } => {
+                                self.go_back();
+                            }
+                            _ => {}
+                        }
+                    }
+                }
                 SelectorMode::ConfirmDelete => {
-                    if let Some(delete_idx) = self.delete_target {
+                    if let Some(delete_path) = self.delete_target.clone() {
                         let projects = self.get_projects()?;
-                        if delete_idx < projects.len() {
-                            let project = &projects[delete_idx];
+                        if let Some(project) = projects.iter().find(|p| p.path == delete_path) {
                             self.render_delete_confirmation(project)?;
                             
-                            if let Event::Key(key) = event::read()? {
+                            if let Some(key) = self.read_key(false)? {
                                 match key {
                                     KeyEvent { code: KeyCode::Char('y'), .. } | KeyEvent { code: KeyCode::Char('Y'), .. } => {
-                                        self.delete_project(project)?;
-                                        self.all_projects = None; // Force reload
-                                        self.mode = SelectorMode::ProjectSelection;
-                                        self.delete_target = None;
-                                        self.cursor_pos = 0;
+                                        if !self.delete_armed {
+                                            // Checked again in case work was left behind since the prompt opened
+                                            self.delete_warnings = unsaved_work(project);
+                                        }
+                                        if !self.delete_warnings.is_empty() && !self.delete_armed {
+                                            // Unsaved work needs a second Y
+                                            self.delete_armed = true;
+                                        } else {
+                                            let deleted = self.delete_project(project, self.delete_permanent);
+                                            if deleted.as_ref().is_err_and(crosses_devices) {
+                                                // Say so and ask again before deleting for good
+                                                self.delete_warnings = vec![format!(
+                                                    "{} is on another filesystem than slop's trash, so this can't be undone.",
+                                                    project.name
+                                                )];
+                                                self.delete_armed = true;
+                                                self.delete_permanent = true;
+                                                continue;
+                                            }
+                                            self.status_message = Some(match deleted {
+                                                Ok(true) => format!("Deleted {} (slop undo brings it back)", project.name),
+                                                Ok(false) => format!("Deleted {} permanently", project.name),
+                                                Err(e) => e.to_string(),
+                                            });
+                                            self.all_projects = None; // Force reload
+                                            self.mode = SelectorMode::ProjectSelection;
+                                            self.delete_target = None;
+                                            self.cursor_pos = 0;
+                                        }
                                     }
                                     _ => {
-                                        self.mode = SelectorMode::ProjectSelection;
-                                        self.delete_target = None;
+                                        self.go_back();
                                     }
                                 }
                             }
+                        } else {
+                            // Gone from the list (deleted elsewhere or filtered out)
+                            self.go_back();
                         }
                     }
                 }
@@ -654,69 +1965,351 @@ impl VibeSelector {
         Ok(self.selected.clone())
     }
 
-    fn is_github_url(&self, input: &str) -> bool {
-        if let Ok(url) = Url::parse(input) {
-            url.host_str() == Some("github.com")
-        } else {
-            // Also accept github.com/user/repo format and user/repo shorthand
-            let github_regex = Regex::new(r"^(github\.com/)?[\w\-\.]+/[\w\-\.]+(/.*)?$").unwrap();
-            github_regex.is_match(input) && !input.contains(' ')
-        }
+    /// F1 anywhere with shortcuts to show; `?` too, unless it would be typed.
+    fn is_help_key(&self, key: &KeyEvent) -> bool {
+        let typing = match self.mode {
+            SelectorMode::ProjectSelection => !self.input_buffer.is_empty(),
+            SelectorMode::EditingPath
+            | SelectorMode::EditingEditor
+            | SelectorMode::ForkName
+            | SelectorMode::WorktreeBranch
+            | SelectorMode::CloneName
+            | SelectorMode::ProjectEditor => true,
+            // Any key answers these prompts, and they spell out their keys already
+            SelectorMode::ConfirmDelete | SelectorMode::ConfirmCollision => return false,
+            _ => false,
+        };
+        key.code == KeyCode::F(1) || (key.code == KeyCode::Char('?') && !is_text_key(key, typing))
     }
 
-    fn render_project_selection(&mut self, projects: &[Project], create_new_text: &str) -> Result<()> {
-        execute!(io::stderr(), Clear(ClearType::All), MoveTo(0, 0))?;
-
-        let separator = "─".repeat(self.term_width.saturating_sub(1).max(10) as usize);
+    /// Every shortcut of the current mode: the single source for both the `?`
+    /// overlay and the footer hints.
+    fn shortcuts(&self) -> Vec<Shortcut> {
+        let back = Shortcut::new(&["Esc", "Ctrl+C"], "Back", true);
+        let help = Shortcut::new(HELP_KEYS, "Help", true);
+        match self.mode {
+            SelectorMode::ProjectSelection => {
+                let keymap = &self.keymap;
+                let bound = |action, label: &'static str, footer| Shortcut { keys: keymap.keys(action), label: label.into(), footer };
+                let navigate: Vec<String> = keymap
+                    .keys(KeyAction::Up)
+                    .into_iter()
+                    .zip(keymap.keys(KeyAction::Down))
+                    .map(|(up, down)| if up == "↑" && down == "↓" { "↑↓".to_string() } else { format!("{}/{}", up, down) })
+                    .collect();
+                let mut shortcuts = vec![
+                    Shortcut::new(&["Type"], "Search", true),
+                    Shortcut { keys: navigate, label: "Navigate".into(), footer: true },
+                    bound(KeyAction::Select, "Select", true),
+                    // Only worth a footer slot when there's something to cycle between
+                    Shortcut {
+                        keys: keymap.keys(KeyAction::Editor),
+                        label: format!("Cycle editors ({})", self.config.default_editor).into(),
+                        footer: self.config.favorite_editors.len() > 1,
+                    },
+                    Shortcut::new(&["Ctrl+S"], "Keep the cycled editor as the default", false),
+                    Shortcut::new(&["Alt+Enter"], "Pick a template for the typed name", false),
+                    Shortcut::new(&["Shift+Enter"], "Clone a URL without opening it", false),
+                    bound(KeyAction::Mark, "Mark", true),
+                    Shortcut::new(&["Alt+1-9"], "Open", true),
+                    bound(KeyAction::Delete, "Delete", true),
+                    Shortcut::new(&["F"], "Fork", true),
+                    Shortcut::new(&["W"], "Worktree", true),
+                    Shortcut::new(&["M"], "Move to another root", false),
+                    Shortcut::new(&["E"], "Set this project's editor", false),
+                    Shortcut::new(&["Y"], "Copy path", true),
+                    Shortcut::new(&["O"], "Open remote", true),
+                    Shortcut::new(&["*"], "Pin", false),
+                    Shortcut::new(&["Ctrl+R", "F5"], "Rescan", false),
+                    Shortcut::new(&["F12"], "Score breakdown", false),
+                    Shortcut::new(&["←→", "Home", "End"], "Move within the search", false),
+                    Shortcut::new(&["Ctrl+V"], "Paste", true),
+                    bound(KeyAction::Config, "Configuration", false),
+                    bound(KeyAction::Clear, "Clear", true),
+                    bound(KeyAction::Quit, "Exit", false),
+                    help,
+                ];
+                shortcuts.retain(|s| !s.keys.is_empty());
+                shortcuts
+            }
+            SelectorMode::TemplateSelection => vec![
+                Shortcut::new(NAVIGATE_KEYS, "Navigate", true),
+                Shortcut::new(&["Enter"], "Select", true),
+                Shortcut::new(&["Type"], "Edit name", true),
+                Shortcut::new(&["/"], "Filter templates", true),
+                Shortcut::new(&["Ctrl+V"], "Paste", false),
+                back,
+                help,
+            ],
+            SelectorMode::Configuration => vec![
+                Shortcut::new(NAVIGATE_KEYS, "Navigate", true),
+                Shortcut::new(&["Enter"], "Edit", true),
+                back,
+                help,
+            ],
+            SelectorMode::PickingEditor | SelectorMode::PickingDestination | SelectorMode::MovingProject => vec![
+                Shortcut::new(NAVIGATE_KEYS, "Navigate", true),
+                Shortcut::new(&["Enter"], "Select", true),
+                back,
+                help,
+            ],
+            SelectorMode::EditingPath
+            | SelectorMode::EditingEditor
+            | SelectorMode::ForkName
+            | SelectorMode::WorktreeBranch
+            | SelectorMode::CloneName
+            | SelectorMode::ProjectEditor => vec![
+                Shortcut::new(&["Type"], "Edit", true),
+                Shortcut::new(&["Enter"], "Save", true),
+                Shortcut::new(&["Esc", "Ctrl+C"], "Cancel", true),
+                Shortcut::new(&["F1"], "Help", false),
+            ],
+            SelectorMode::ConfirmDelete | SelectorMode::ConfirmCollision => Vec::new(),
+        }
+    }
+
+    /// `Key: Label` for the footer-worthy shortcuts, shortest key first.
+    fn footer_hints(&self) -> String {
+        self.shortcuts()
+            .iter()
+            .filter(|s| s.footer)
+            .filter_map(|s| {
+                let key = s.keys.iter().min_by_key(|k| k.chars().count())?;
+                Some(format!("{}: {}", key, s.label))
+            })
+            .collect::<Vec<_>>()
+            .join("  ")
+    }
+
+    fn render_help(&self) -> Result<()> {
+        let shortcuts = self.shortcuts();
+        if self.config.accessible {
+            let lines: Vec<String> = shortcuts.iter().map(|s| format!("{}: {}", s.keys.join(" or "), s.label)).collect();
+            return self.announce(&format!("Shortcuts. {}. Any key closes this", lines.join(". ")));
+        }
+
+        execute!(io::stderr(), Clear(ClearType::All), MoveTo(0, 0))?;
+
+        let separator = "─".repeat(self.term_width.saturating_sub(1).max(1) as usize);
 
-        // Header
         execute!(
             io::stderr(),
-            SetForegroundColor(Color::Cyan),
-            Print("slop"),
+            self.paint(Hue::Header),
+            Print(self.fit(&format!("{} · shortcuts", self.breadcrumb()), 0)),
             ResetColor,
             Print("\r\n"),
-            SetForegroundColor(Color::DarkGrey),
+            self.paint(Hue::Meta),
             Print(&separator),
             ResetColor,
             Print("\r\n"),
         )?;
 
-        // Search input
-        if self.input_buffer.is_empty() {
+        let key_width = shortcuts.iter().map(|s| s.keys.join(", ").chars().count()).max().unwrap_or(0);
+        let (max_visible, _) = self.list_layout(2);
+        for shortcut in shortcuts.iter().take(max_visible) {
+            let keys = shortcut.keys.join(", ");
+            let padding = " ".repeat(key_width - keys.chars().count());
             execute!(
                 io::stderr(),
-                SetForegroundColor(Color::DarkGrey),
-                Print("Search or paste GitHub URL"),
+                Print("  "),
+                self.paint(Hue::Selection),
+                Print(&keys),
                 ResetColor,
+                Print(self.fit(&format!("{}  {}", padding, shortcut.label), 2 + keys.chars().count())),
                 Print("\r\n"),
             )?;
-        } else if self.is_github_url(&self.input_buffer) {
+        }
+
+        let note = if self.mode == SelectorMode::ProjectSelection {
+            "Letter keys act only while the search is empty  Any key: Close"
+        } else {
+            "Any key: Close"
+        };
+        execute!(
+            io::stderr(),
+            self.paint(Hue::Meta),
+            Print(&separator),
+            Print("\r\n"),
+            Print(self.fit(note, 0)),
+            ResetColor,
+        )?;
+
+        io::stderr().flush()?;
+        Ok(())
+    }
+
+    /// `slop › Configuration › Editor`: the way back up from the current mode.
+    fn breadcrumb(&self) -> String {
+        let mut trail = vec![self.mode.title()];
+        let mut mode = self.mode.clone();
+        while let Some(parent) = mode.parent() {
+            trail.push(parent.title());
+            mode = parent;
+        }
+        trail.reverse();
+        trail.join(" › ")
+    }
+
+    /// Esc (and Ctrl-C) in every mode: one level up `SelectorMode::parent`,
+    /// dropping whatever the mode being left was holding. In the project list
+    /// that means clearing the search; returns false when there's nothing
+    /// left to go back from, so the navigator exits.
+    fn go_back(&mut self) -> bool {
+        let Some(parent) = self.mode.parent() else {
+            if self.input_buffer.is_empty() {
+                return false;
+            }
+            self.input_buffer.clear();
+            self.input_cursor_back = 0;
+            self.cursor_pos = 0;
+            return true;
+        };
+
+        // Rows may have moved since, so find the project that was acted on again
+        let delete_row = match self.delete_target.clone() {
+            Some(path) => self.get_projects().ok().and_then(|projects| projects.iter().position(|p| p.path == path)),
+            None => None,
+        };
+        self.cursor_pos = match self.mode {
+            // Back on the row that was acted on
+            SelectorMode::ConfirmDelete => delete_row.unwrap_or(0),
+            SelectorMode::ForkName | SelectorMode::WorktreeBranch | SelectorMode::ProjectEditor => self.cursor_pos,
+            SelectorMode::MovingProject => self.move_source.as_ref().map_or(0, |(_, row)| *row),
+            SelectorMode::PickingEditor => 1,
+            SelectorMode::EditingEditor => installed_editors().len(), // "Other command…"
+            _ => 0,
+        };
+        // Inline edits own the input; everything else shares the search
+        if matches!(
+            self.mode,
+            SelectorMode::EditingPath
+                | SelectorMode::EditingEditor
+                | SelectorMode::ForkName
+                | SelectorMode::WorktreeBranch
+                | SelectorMode::CloneName
+                | SelectorMode::ProjectEditor
+        ) {
+            self.input_buffer.clear();
+        }
+        // Back to the URL that was typed
+        if let Some((_, query)) = self.clone_pending.take() {
+            self.input_buffer = query;
+        }
+        self.fork_source = None;
+        self.worktree_source = None;
+        self.move_source = None;
+        self.editor_source = None;
+        self.pending_destination = None;
+        self.name_collision = None;
+        self.delete_target = None;
+        self.status_message = None;
+        self.mode = parent;
+        true
+    }
+
+    /// Next key press. A bracketed paste arrives whole and, where text is
+    /// accepted, goes straight into the input instead of through the key filters.
+    fn read_key(&mut self, accepts_text: bool) -> Result<Option<KeyEvent>> {
+        match event::read()? {
+            Event::Key(key) if !self.show_help && self.is_help_key(&key) => {
+                self.show_help = true;
+                Ok(None)
+            }
+            Event::Key(key) => Ok(Some(key)),
+            Event::Paste(text) if accepts_text => {
+                let text = sanitize_paste(&text);
+                if self.mode == SelectorMode::ProjectSelection {
+                    let at = self.input_cursor_index();
+                    self.input_buffer.insert_str(at, &text);
+                    self.status_message = None;
+                    self.cursor_pos = 0;
+                } else {
+                    self.input_buffer.push_str(&text);
+                }
+                Ok(None)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn is_github_url(&self, input: &str) -> bool {
+        let (input, _) = split_sparse_path(input);
+        if let Ok(url) = Url::parse(input) {
+            url.host_str() == Some("github.com")
+        } else {
+            // Also accept github.com/user/repo format and user/repo shorthand
+            let github_regex = Regex::new(r"^(github\.com/)?[\w\-\.]+/[\w\-\.]+(/.*)?$").unwrap();
+            github_regex.is_match(input) && !input.contains(' ')
+        }
+    }
+
+    fn render_project_selection(&mut self, projects: &[Project], create_new_text: &str) -> Result<()> {
+        if self.config.accessible {
+            return self.announce_project_selection(projects, create_new_text);
+        }
+
+        execute!(io::stderr(), Clear(ClearType::All), MoveTo(0, 0))?;
+
+        let separator = "─".repeat(self.term_width.saturating_sub(1).max(1) as usize);
+
+        // Header
+        execute!(io::stderr(), self.paint(Hue::Header), Print("slop"), ResetColor)?;
+        if let Some(scan) = &self.scan {
+            let status = match &scan.fresh {
+                Some(_) => "  refreshing…".to_string(),
+                None => format!("  scanning… ({} found)", self.all_projects.as_ref().map_or(0, Vec::len)),
+            };
+            execute!(io::stderr(), self.paint(Hue::Meta), Print(status), ResetColor)?;
+        }
+        execute!(
+            io::stderr(),
+            Print("\r\n"),
+            self.paint(Hue::Meta),
+            Print(&separator),
+            ResetColor,
+            Print("\r\n"),
+        )?;
+
+        // Search input
+        if self.input_buffer.is_empty() {
             execute!(
                 io::stderr(),
-                SetForegroundColor(Color::Green),
-                Print("🌐 "),
-                Print(&self.input_buffer),
+                self.paint(Hue::Meta),
+                Print("Search or paste GitHub URL"),
                 ResetColor,
                 Print("\r\n"),
             )?;
+        } else if self.is_github_url(&self.input_buffer) {
+            execute!(io::stderr(), self.paint(Hue::Success), Print("🌐 "))?;
+            self.render_search_input()?;
+            execute!(io::stderr(), ResetColor, Print("\r\n"))?;
         } else {
-            execute!(
-                io::stderr(),
-                Print(&self.input_buffer),
-                Print("\r\n"),
-            )?;
+            self.render_search_input()?;
+            execute!(io::stderr(), Print("\r\n"))?;
         }
         execute!(
             io::stderr(),
-            SetForegroundColor(Color::DarkGrey),
+            self.paint(Hue::Meta),
             Print(&separator),
             ResetColor,
             Print("\r\n"),
         )?;
 
-        // Calculate visible window
-        let max_visible = (self.term_height as usize).saturating_sub(8).max(3);
+        // Empty state - distinguish "nothing matched" from "nothing here yet"
+        if projects.is_empty() {
+            execute!(
+                io::stderr(),
+                self.paint(Hue::Meta),
+                Print(self.empty_state_text()),
+                ResetColor,
+                Print("\r\n"),
+            )?;
+        }
+
+        // Calculate visible window: header, search and separators take 4 lines
+        let score_lines = self.score_panel(projects);
+        let chrome = if projects.is_empty() { 5 } else { 4 } + score_lines.len();
+        let (max_visible, show_footer) = self.list_layout(chrome);
         let total_items = projects.len() + 2; // +1 for create new, +1 for config
 
         // Adjust scroll window
@@ -729,6 +2322,7 @@ impl VibeSelector {
         // Display items
         let visible_end = (self.scroll_offset + max_visible).min(total_items);
 
+        let duplicates = self.duplicate_notes();
         for idx in self.scroll_offset..visible_end {
             let is_selected = idx == self.cursor_pos;
             
@@ -736,7 +2330,7 @@ impl VibeSelector {
             if is_selected {
                 execute!(
                     io::stderr(),
-                    SetForegroundColor(Color::Yellow),
+                    self.paint(Hue::Selection),
                     Print("▶ "),
                     ResetColor
                 )?;
@@ -746,25 +2340,27 @@ impl VibeSelector {
 
             if idx < projects.len() {
                 let project = &projects[idx];
-                self.render_project(project, is_selected)?;
+                let hint = idx - self.scroll_offset + 1;
+                let note = duplicates.get(&project.path).map(String::as_str);
+                self.render_project(project, is_selected, (hint <= 9).then_some(hint), note)?;
             } else if idx == projects.len() {
                 // Create new option
                 if is_selected {
                     execute!(
                         io::stderr(),
-                        SetForegroundColor(Color::Yellow),
-                        Print(&create_new_text),
+                        self.paint(Hue::Selection),
+                        Print(self.fit(create_new_text, 2)),
                         ResetColor
                     )?;
                 } else {
-                    execute!(io::stderr(), Print(&create_new_text))?;
+                    execute!(io::stderr(), Print(self.fit(create_new_text, 2)))?;
                 }
             } else {
                 // Configuration option
                 if is_selected {
                     execute!(
                         io::stderr(),
-                        SetForegroundColor(Color::Yellow),
+                        self.paint(Hue::Selection),
                         Print("⚙️  Configure"),
                         ResetColor
                     )?;
@@ -776,34 +2372,126 @@ impl VibeSelector {
             execute!(io::stderr(), Print("\r\n"))?;
         }
 
+        for line in &score_lines {
+            execute!(io::stderr(), self.paint(Hue::Meta), Print(self.fit(line, 0)), ResetColor, Print("\r\n"))?;
+        }
 
-        // Instructions at bottom
-        execute!(
-            io::stderr(),
-            SetForegroundColor(Color::DarkGrey),
-            Print(&separator),
-            Print("\r\n"),
-            Print("Type: Project name  ↑↓: Navigate  Enter: Select  D: Delete  Cmd+V: Paste  ESC: Clear"),
-            ResetColor,
-        )?;
+        // Instructions at bottom (a pending status message takes their place)
+        if show_footer {
+            execute!(
+                io::stderr(),
+                self.paint(Hue::Meta),
+                Print(&separator),
+                Print("\r\n"),
+            )?;
+            if let Some(message) = &self.status_message {
+                execute!(io::stderr(), self.paint(Hue::Success), Print(self.fit(message, 0)), ResetColor)?;
+            } else {
+                execute!(
+                    io::stderr(),
+                    self.paint(Hue::Meta),
+                    Print(self.fit(&self.footer_hints(), 0)),
+                    ResetColor,
+                )?;
+            }
+        }
 
         io::stderr().flush()?;
         Ok(())
     }
 
+    /// Switch to the next of `favorite_editors` for this session only.
+    fn cycle_editor(&mut self) {
+        let favorites = &self.config.favorite_editors;
+        if favorites.is_empty() {
+            self.status_message = Some("Add favorite_editors = [\"claude\", \"cursor\"] to the config to switch editors here".to_string());
+            return;
+        }
+        let next = favorites
+            .iter()
+            .position(|e| *e == self.config.default_editor)
+            .map_or(0, |idx| (idx + 1) % favorites.len());
+        self.config.default_editor = favorites[next].clone();
+        self.status_message = Some(format!("Opening in {} this session - Ctrl+S makes it the default", self.config.default_editor));
+    }
+
+    /// Save the cycled editor as `default_editor`.
+    fn keep_editor(&mut self) {
+        let Ok(config_path) = get_config_file_path() else {
+            return;
+        };
+        let mut saved = load_config(&config_path).unwrap_or_default();
+        if saved.default_editor == self.config.default_editor {
+            return;
+        }
+        saved.default_editor = self.config.default_editor.clone();
+        self.status_message = Some(match save_config(&saved) {
+            Ok(()) => format!("✅ {} is now the default editor", saved.default_editor),
+            Err(e) => format!("Couldn't save the config: {}", e),
+        });
+    }
+
+    /// The F12 panel: how the selected project's score adds up, with the
+    /// characters the search matched in [brackets]. Empty when it's off.
+    fn score_panel(&self, projects: &[Project]) -> Vec<String> {
+        if !self.show_score {
+            return Vec::new();
+        }
+        let Some(project) = projects.get(self.cursor_pos) else {
+            return vec!["score: no project selected".to_string()];
+        };
+        let parts = score_breakdown(&project.name, &self.input_buffer, &project.created, &project.last_accessed);
+        let lowered: Vec<char> = project.name.to_lowercase().chars().collect();
+        // Bracket the original name unless lowercasing changed its length
+        let chars: Vec<char> = if project.name.chars().count() == lowered.len() { project.name.chars().collect() } else { lowered };
+        let marked: String = chars
+            .iter()
+            .enumerate()
+            .map(|(idx, ch)| if parts.matched.contains(&idx) { format!("[{}]", ch) } else { ch.to_string() })
+            .collect();
+        vec![
+            format!(
+                "score {:.2} = match {:.2} + created {:.2} + access {:.2}",
+                parts.total, parts.match_score, parts.created_bonus, parts.access_bonus
+            ),
+            format!("matched: {}", marked),
+        ]
+    }
+
+    /// Templates whose name contains the `/` filter text, in the usual order.
+    fn filtered_templates(&self) -> Vec<ProjectTemplate> {
+        let filter = self.template_filter.as_deref().unwrap_or_default().to_lowercase();
+        ProjectTemplate::get_all()
+            .into_iter()
+            .filter(|template| template.display_name().to_lowercase().contains(&filter))
+            .collect()
+    }
+
     fn render_template_selection(&mut self, templates: &[ProjectTemplate]) -> Result<()> {
+        if self.config.accessible && templates.is_empty() {
+            return self.announce("No templates match");
+        }
+        if self.config.accessible {
+            return self.announce(&format!(
+                "Template {} of {}: {}",
+                self.cursor_pos + 1,
+                templates.len(),
+                templates[self.cursor_pos].display_name()
+            ));
+        }
+
         execute!(io::stderr(), Clear(ClearType::All), MoveTo(0, 0))?;
 
-        let separator = "─".repeat(self.term_width.saturating_sub(1).max(10) as usize);
+        let separator = "─".repeat(self.term_width.saturating_sub(1).max(1) as usize);
 
         // Header
         execute!(
             io::stderr(),
-            SetForegroundColor(Color::Cyan),
-            Print("✨ Choose Project Template"),
+            self.paint(Hue::Header),
+            Print(self.breadcrumb()),
             ResetColor,
             Print("\r\n"),
-            SetForegroundColor(Color::DarkGrey),
+            self.paint(Hue::Meta),
             Print(&separator),
             ResetColor,
             Print("\r\n"),
@@ -813,7 +2501,7 @@ impl VibeSelector {
         if !self.input_buffer.is_empty() {
             execute!(
                 io::stderr(),
-                SetForegroundColor(Color::Green),
+                self.paint(Hue::Success),
                 Print("Creating: "),
                 Print(&self.input_buffer),
                 ResetColor,
@@ -822,24 +2510,39 @@ impl VibeSelector {
         } else {
             execute!(
                 io::stderr(),
-                SetForegroundColor(Color::DarkGrey),
-                Print("Creating: new-project"),
+                self.paint(Hue::Meta),
+                Print(format!("Creating: {}", resolve_default_project_name(&self.config, &self.base_path))),
                 ResetColor,
                 Print("\r\n"),
             )?;
         }
         execute!(
             io::stderr(),
-            SetForegroundColor(Color::DarkGrey),
+            self.paint(Hue::Meta),
             Print(&separator),
             ResetColor,
             Print("\r\n"),
         )?;
 
-        for (idx, template) in templates.iter().enumerate() {
+        if let Some(filter) = &self.template_filter {
+            execute!(io::stderr(), Print("Filter: "), Print(filter), Print("\r\n"))?;
+        }
+        if templates.is_empty() {
+            execute!(io::stderr(), self.paint(Hue::Meta), Print("  No templates match"), ResetColor, Print("\r\n"))?;
+        }
+
+        let chrome = 4 + usize::from(self.template_filter.is_some()) + usize::from(templates.is_empty());
+        let (max_visible, show_footer) = self.list_layout(chrome);
+        if self.cursor_pos < self.scroll_offset {
+            self.scroll_offset = self.cursor_pos;
+        } else if self.cursor_pos >= self.scroll_offset + max_visible {
+            self.scroll_offset = self.cursor_pos.saturating_sub(max_visible - 1);
+        }
+
+        for (idx, template) in templates.iter().enumerate().skip(self.scroll_offset).take(max_visible) {
             let is_selected = idx == self.cursor_pos;
             if is_selected {
-                execute!(io::stderr(), SetForegroundColor(Color::Yellow), Print("→ "), ResetColor)?;
+                execute!(io::stderr(), self.paint(Hue::Selection), Print("→ "), ResetColor)?;
             } else {
                 execute!(io::stderr(), Print("  "))?;
             }
@@ -848,32 +2551,44 @@ impl VibeSelector {
         }
 
         // Instructions at bottom
-        execute!(
-            io::stderr(),
-            SetForegroundColor(Color::DarkGrey),
-            Print(&separator),
-            Print("\r\n"),
-            Print("↑↓: Navigate  Enter: Select  Type: Edit name  ESC: Back"),
-            ResetColor,
-        )?;
+        if show_footer {
+            execute!(
+                io::stderr(),
+                self.paint(Hue::Meta),
+                Print(&separator),
+                Print("\r\n"),
+                Print(self.fit(&self.footer_hints(), 0)),
+                ResetColor,
+            )?;
+        }
 
         io::stderr().flush()?;
         Ok(())
     }
 
     fn render_configuration_interface(&mut self) -> Result<()> {
+        if self.config.accessible {
+            let config = load_config(&get_config_file_path()?).unwrap_or_default();
+            let line = match self.cursor_pos {
+                0 => format!("Setting 1 of 3: Projects Path, {}", config.projects_path.display()),
+                1 => format!("Setting 2 of 3: Editor, {}", config.default_editor),
+                _ => "Setting 3 of 3: Back".to_string(),
+            };
+            return self.announce(&line);
+        }
+
         execute!(io::stderr(), Clear(ClearType::All), MoveTo(0, 0))?;
 
-        let separator = "─".repeat(self.term_width.saturating_sub(1).max(10) as usize);
+        let separator = "─".repeat(self.term_width.saturating_sub(1).max(1) as usize);
 
         // Header - match main UI style
         execute!(
             io::stderr(),
-            SetForegroundColor(Color::Cyan),
-            Print("⚙️  Configuration"),
+            self.paint(Hue::Header),
+            Print(self.breadcrumb()),
             ResetColor,
             Print("\r\n"),
-            SetForegroundColor(Color::DarkGrey),
+            self.paint(Hue::Meta),
             Print(&separator),
             ResetColor,
             Print("\r\n"),
@@ -900,7 +2615,7 @@ impl VibeSelector {
             if is_selected {
                 execute!(
                     io::stderr(),
-                    SetForegroundColor(Color::Yellow),
+                    self.paint(Hue::Selection),
                     Print("▶ "),
                     ResetColor
                 )?;
@@ -912,7 +2627,7 @@ impl VibeSelector {
             if is_selected {
                 execute!(
                     io::stderr(),
-                    SetForegroundColor(Color::Yellow),
+                    self.paint(Hue::Selection),
                     Print(label),
                     ResetColor,
                 )?;
@@ -924,7 +2639,7 @@ impl VibeSelector {
                 execute!(
                     io::stderr(),
                     Print(": "),
-                    SetForegroundColor(Color::Cyan),
+                    self.paint(Hue::Header),
                     Print(value),
                     ResetColor,
                 )?;
@@ -935,30 +2650,170 @@ impl VibeSelector {
         // Instructions at bottom
         execute!(
             io::stderr(),
-            SetForegroundColor(Color::DarkGrey),
+            self.paint(Hue::Meta),
+            Print(&separator),
+            Print("\r\n"),
+            Print(self.fit(&self.footer_hints(), 0)),
+            ResetColor,
+        )?;
+
+        io::stderr().flush()?;
+        Ok(())
+    }
+
+    fn render_editor_picker(&self, editors: &[&str], current: &str) -> Result<()> {
+        let current_program = editor_program(current);
+        if self.config.accessible {
+            let line = match editors.get(self.cursor_pos) {
+                Some(editor) => format!(
+                    "Editor {} of {}: {}{}",
+                    self.cursor_pos + 1,
+                    editors.len() + 1,
+                    editor,
+                    if *editor == current_program { ", current" } else { "" }
+                ),
+                None => format!("Editor {} of {}: Other command, {}", editors.len() + 1, editors.len() + 1, current),
+            };
+            return self.announce(&line);
+        }
+
+        execute!(io::stderr(), Clear(ClearType::All), MoveTo(0, 0))?;
+
+        let separator = "─".repeat(self.term_width.saturating_sub(1).max(1) as usize);
+
+        execute!(
+            io::stderr(),
+            self.paint(Hue::Header),
+            Print(self.breadcrumb()),
+            ResetColor,
+            Print("\r\n"),
+            self.paint(Hue::Meta),
             Print(&separator),
+            ResetColor,
+            Print("\r\n"),
+        )?;
+
+        if editors.is_empty() {
+            execute!(
+                io::stderr(),
+                self.paint(Hue::Meta),
+                Print(self.fit(&format!("  None of {} found in PATH", KNOWN_EDITORS.join(", ")), 0)),
+                ResetColor,
+                Print("\r\n"),
+            )?;
+        }
+
+        let (max_visible, show_footer) = self.list_layout(2);
+        let start = self.cursor_pos.saturating_sub(max_visible - 1);
+        let other = format!("Other command… ({})", current);
+        let rows = editors.iter().copied().chain(std::iter::once(other.as_str()));
+
+        for (idx, label) in rows.enumerate().skip(start).take(max_visible) {
+            if idx == self.cursor_pos {
+                execute!(io::stderr(), self.paint(Hue::Selection), Print("→ "), ResetColor)?;
+            } else {
+                execute!(io::stderr(), Print("  "))?;
+            }
+            execute!(io::stderr(), Print(self.fit(label, 2)))?;
+            if idx < editors.len() && label == current_program {
+                execute!(io::stderr(), self.paint(Hue::Success), Print(" ✓"), ResetColor)?;
+            }
+            execute!(io::stderr(), Print("\r\n"))?;
+        }
+
+        if show_footer {
+            execute!(
+                io::stderr(),
+                self.paint(Hue::Meta),
+                Print(&separator),
+                Print("\r\n"),
+                Print(self.fit(&self.footer_hints(), 0)),
+                ResetColor,
+            )?;
+        }
+
+        io::stderr().flush()?;
+        Ok(())
+    }
+
+    fn render_destination_picker(&self, header: &str) -> Result<()> {
+        if self.config.accessible {
+            return self.announce(&format!(
+                "{} {} of {}: {}",
+                strip_emoji(header).trim(),
+                self.cursor_pos + 1,
+                self.roots.len(),
+                self.roots[self.cursor_pos].display()
+            ));
+        }
+
+        execute!(io::stderr(), Clear(ClearType::All), MoveTo(0, 0))?;
+
+        let separator = "─".repeat(self.term_width.saturating_sub(1).max(1) as usize);
+
+        execute!(
+            io::stderr(),
+            self.paint(Hue::Header),
+            Print(self.fit(header, 0)),
+            ResetColor,
             Print("\r\n"),
-            Print("↑↓: Navigate  Enter: Edit  ESC: Back"),
+            self.paint(Hue::Meta),
+            Print(&separator),
             ResetColor,
+            Print("\r\n"),
         )?;
 
+        let (max_visible, show_footer) = self.list_layout(2);
+        let start = self.cursor_pos.saturating_sub(max_visible - 1);
+        for (idx, root) in self.roots.iter().enumerate().skip(start).take(max_visible) {
+            if idx == self.cursor_pos {
+                execute!(
+                    io::stderr(),
+                    self.paint(Hue::Selection),
+                    Print("→ "),
+                    Print(self.fit(&root.display().to_string(), 2)),
+                    ResetColor,
+                )?;
+            } else {
+                execute!(io::stderr(), Print("  "), Print(self.fit(&root.display().to_string(), 2)))?;
+            }
+            execute!(io::stderr(), Print("\r\n"))?;
+        }
+
+        if show_footer {
+            execute!(
+                io::stderr(),
+                self.paint(Hue::Meta),
+                Print(&separator),
+                Print("\r\n"),
+                Print(self.fit(&self.footer_hints(), 0)),
+                ResetColor,
+            )?;
+        }
+
         io::stderr().flush()?;
         Ok(())
     }
 
-    fn render_inline_edit(&self, label: &str, value: &str) -> Result<()> {
+    fn render_inline_edit(&self, label: &str, value: &str, hint: Option<(Hue, String)>) -> Result<()> {
+        if self.config.accessible {
+            let status = self.status_message.as_deref().map(|m| format!(". {}", m)).unwrap_or_default();
+            let hint = hint.map(|(_, text)| format!(". {}", strip_emoji(&text).trim())).unwrap_or_default();
+            return self.announce(&format!("Editing {}: {}{}{}", strip_emoji(label).trim(), value, hint, status));
+        }
+
         execute!(io::stderr(), Clear(ClearType::All), MoveTo(0, 0))?;
 
-        let separator = "─".repeat(self.term_width.saturating_sub(1).max(10) as usize);
+        let separator = "─".repeat(self.term_width.saturating_sub(1).max(1) as usize);
 
         // Header - match main UI style
         execute!(
             io::stderr(),
-            SetForegroundColor(Color::Cyan),
-            Print("⚙️  Configuration"),
+            self.paint(Hue::Header),
+            Print(self.breadcrumb()),
             ResetColor,
             Print("\r\n"),
-            SetForegroundColor(Color::DarkGrey),
+            self.paint(Hue::Meta),
             Print(&separator),
             ResetColor,
             Print("\r\n"),
@@ -967,7 +2822,7 @@ impl VibeSelector {
         // Edit field with consistent selection highlighting
         execute!(
             io::stderr(),
-            SetForegroundColor(Color::Yellow),
+            self.paint(Hue::Selection),
             Print("▶ "),
             Print(label),
             Print(": "),
@@ -977,13 +2832,34 @@ impl VibeSelector {
             Print("\r\n"),
         )?;
 
+        if let Some((hue, text)) = hint {
+            execute!(
+                io::stderr(),
+                self.paint(hue),
+                Print("  "),
+                Print(text),
+                ResetColor,
+                Print("\r\n"),
+            )?;
+        }
+
+        if let Some(message) = &self.status_message {
+            execute!(
+                io::stderr(),
+                self.paint(Hue::Error),
+                Print(message),
+                ResetColor,
+                Print("\r\n"),
+            )?;
+        }
+
         // Instructions - match main UI style
         execute!(
             io::stderr(),
-            SetForegroundColor(Color::DarkGrey),
+            self.paint(Hue::Meta),
             Print(&separator),
             Print("\r\n"),
-            Print("Type to edit  Enter: Save  ESC: Cancel"),
+            Print(self.fit(&self.footer_hints(), 0)),
             ResetColor,
         )?;
 
@@ -991,53 +2867,132 @@ impl VibeSelector {
         Ok(())
     }
 
-    fn render_delete_confirmation(&self, project: &Project) -> Result<()> {
+    fn render_name_collision(&self, existing: &Path, suggested: &Path) -> Result<()> {
+        let name = |path: &Path| path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+        let prompt = format!("Enter/O: Open it  N: Create {}  Any other key: Cancel", name(suggested));
+
+        if self.config.accessible {
+            return self.announce(&format!("{} already exists at {}. {}", name(existing), existing.display(), prompt));
+        }
+
         execute!(io::stderr(), Clear(ClearType::All), MoveTo(0, 0))?;
 
-        let separator = "─".repeat(self.term_width.saturating_sub(1).max(10) as usize);
+        let separator = "─".repeat(self.term_width.saturating_sub(1).max(1) as usize);
 
-        // Header
         execute!(
             io::stderr(),
-            SetForegroundColor(Color::Red),
-            Print("🗑️  Delete Project"),
+            self.paint(Hue::Header),
+            Print(self.breadcrumb()),
             ResetColor,
             Print("\r\n"),
-            SetForegroundColor(Color::DarkGrey),
+            self.paint(Hue::Meta),
             Print(&separator),
             ResetColor,
             Print("\r\n"),
-        )?;
-
-        // Warning
-        execute!(
+            self.paint(Hue::Warning),
+            Print("⚠️  "),
+            self.paint(Hue::Header),
+            Print(name(existing)),
+            self.paint(Hue::Warning),
+            Print(" already exists"),
+            ResetColor,
+            Print("\r\n"),
+            self.paint(Hue::Meta),
+            Print(self.fit(&format!("   {}", existing.display()), 0)),
+            ResetColor,
+            Print("\r\n"),
+            self.paint(Hue::Meta),
+            Print(&separator),
+            Print("\r\n"),
+            Print(self.fit(&prompt, 0)),
+            ResetColor,
+        )?;
+
+        io::stderr().flush()?;
+        Ok(())
+    }
+
+    fn render_delete_confirmation(&self, project: &Project) -> Result<()> {
+        let prompt = if self.delete_warnings.is_empty() {
+            "Y: Delete  Any other key: Cancel"
+        } else if self.delete_permanent {
+            "Y again: Delete permanently  Any other key: Cancel"
+        } else if self.delete_armed {
+            "Y again: Delete anyway  Any other key: Cancel"
+        } else {
+            "Y: Continue (asks again)  Any other key: Cancel"
+        };
+
+        if self.config.accessible {
+            let warnings: Vec<&str> = self.delete_warnings.iter().map(|w| w.trim()).collect();
+            let warnings = if warnings.is_empty() { String::new() } else { format!(" Warning: {}.", warnings.join(" ")) };
+            return self.announce(&format!(
+                "Delete {} at {}?{} {}",
+                project.name,
+                project.path.display(),
+                warnings,
+                prompt
+            ));
+        }
+
+        execute!(io::stderr(), Clear(ClearType::All), MoveTo(0, 0))?;
+
+        let separator = "─".repeat(self.term_width.saturating_sub(1).max(1) as usize);
+
+        // Header
+        execute!(
+            io::stderr(),
+            self.paint(Hue::Error),
+            Print(self.breadcrumb()),
+            ResetColor,
+            Print("\r\n"),
+            self.paint(Hue::Meta),
+            Print(&separator),
+            ResetColor,
+            Print("\r\n"),
+        )?;
+
+        // Warning
+        execute!(
             io::stderr(),
-            SetForegroundColor(Color::Yellow),
+            self.paint(Hue::Warning),
             Print("⚠️  Delete "),
-            SetForegroundColor(Color::Cyan),
+            self.paint(Hue::Header),
             Print(&project.name),
-            SetForegroundColor(Color::Yellow),
+            self.paint(Hue::Warning),
             Print("?"),
             ResetColor,
             Print("\r\n"),
-            SetForegroundColor(Color::DarkGrey),
+            self.paint(Hue::Meta),
             Print(&format!("   {}", project.path.display())),
             ResetColor,
             Print("\r\n"),
             Print("\r\n"),
-            SetForegroundColor(Color::Red),
-            Print("This will permanently delete the entire folder!"),
+            self.paint(Hue::Error),
+            Print(if self.delete_permanent {
+                "This will delete the entire folder for good!"
+            } else {
+                "This will delete the entire folder! (`slop undo` can restore the last one)"
+            }),
             ResetColor,
             Print("\r\n"),
         )?;
 
+        if !self.delete_warnings.is_empty() {
+            execute!(io::stderr(), Print("\r\n"), self.paint(Hue::Warning))?;
+            for warning in &self.delete_warnings {
+                execute!(io::stderr(), Print(warning), Print("\r\n"))?;
+            }
+            execute!(io::stderr(), ResetColor)?;
+        }
+
         // Instructions
         execute!(
             io::stderr(),
-            SetForegroundColor(Color::DarkGrey),
+            self.paint(Hue::Meta),
             Print(&separator),
             Print("\r\n"),
-            Print("Y: Delete  Any other key: Cancel"),
+            Print(prompt),
             ResetColor,
         )?;
 
@@ -1045,134 +3000,512 @@ impl VibeSelector {
         Ok(())
     }
 
-    fn delete_project(&self, project: &Project) -> Result<()> {
+    fn find_project_by_name(&mut self, name: &str) -> Result<Project> {
+        self.load_all_projects()?;
+        if let Some(project) = self.all_projects.as_ref().unwrap().iter().find(|p| p.name == name) {
+            return Ok(project.clone());
+        }
+
+        self.input_buffer = name.to_string();
+        let matches = self.get_projects()?;
+        match matches.len() {
+            0 => Err(anyhow::anyhow!("No project matches '{}'", name)),
+            1 => Ok(matches[0].clone()),
+            _ => {
+                let names: Vec<&str> = matches.iter().map(|p| p.name.as_str()).collect();
+                Err(anyhow::anyhow!("'{}' is ambiguous, matches: {}", name, names.join(", ")))
+            }
+        }
+    }
+
+    fn fork_project(&self, source: &Project, new_name: &str) -> Result<()> {
+        let new_name = new_name.trim().replace(' ', "-");
+        validate_project_name(&new_name)?;
+
+        let destination = self.base_path.join(&new_name);
+        if destination.exists() {
+            return Err(anyhow::anyhow!("'{}' already exists", new_name));
+        }
+
+        copy_dir_recursive(&source.path, &destination, &[".git", "target", "node_modules"])
+            .with_context(|| format!("Failed to fork {} into {}", source.name, destination.display()))?;
+        record_last_action(&LastAction::Created(destination))
+    }
+
+    /// Returns whether `name` is pinned afterwards.
+    fn toggle_pin(&mut self, name: &str) -> Result<bool> {
+        let mut config = load_config(&get_config_file_path()?).unwrap_or_default();
+        let now_pinned = if let Some(idx) = config.pinned.iter().position(|p| p == name) {
+            config.pinned.remove(idx);
+            false
+        } else {
+            config.pinned.push(name.to_string());
+            true
+        };
+        save_config(&config)?;
+        self.config.pinned = config.pinned;
+        Ok(now_pinned)
+    }
+
+    /// Move a project folder into another root, carrying its history along.
+    /// Returns where it ended up.
+    fn move_to_root(&mut self, project: &Project, root: &Path) -> Result<PathBuf> {
+        let name = project.path.file_name().ok_or_else(|| anyhow::anyhow!("no folder name"))?;
+        let mut destination = root.join(name);
+        if destination.exists() {
+            destination = suffixed_project_path(&destination);
+        }
+
+        // Roots on different filesystems can't rename; copy then remove instead
+        if fs::rename(&project.path, &destination).is_err() {
+            copy_dir_recursive(&project.path, &destination, &[])
+                .with_context(|| format!("Failed to copy to {}", destination.display()))?;
+            fs::remove_dir_all(&project.path)
+                .with_context(|| format!("Copied to {} but failed to remove the original", destination.display()))?;
+        }
+
+        if let Err(e) = move_access_history(&project.path, &destination) {
+            say_err!("⚠️  Could not update history: {}", e);
+        }
+        if let Some(editor) = load_project_editor(&project.path) {
+            if let Err(e) = save_project_editor(&project.path, None).and_then(|_| save_project_editor(&destination, Some(&editor))) {
+                say_err!("⚠️  Could not move the project's editor setting: {}", e);
+            }
+        }
+        if let Some(projects) = self.all_projects.as_mut() {
+            if let Some(moved) = projects.iter_mut().find(|p| p.path == project.path) {
+                moved.name = destination.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+                moved.path = destination.clone();
+                moved.root = root.to_path_buf();
+            }
+        }
+        for marked in self.marked.iter_mut().filter(|p| **p == project.path) {
+            *marked = destination.clone();
+        }
+        self.save_index();
+        Ok(destination)
+    }
+
+    /// Returns whether the project went to the trash (and so `slop undo` can restore it).
+    /// When the trash is on another filesystem the delete is permanent, but only if
+    /// `permanent` allows it; otherwise that error is returned for the caller to ask.
+    fn delete_project(&self, project: &Project, permanent: bool) -> Result<bool> {
+        check_deletable(&project.path, &self.roots, self.config.scan_depth)?;
+        match move_to_trash(&project.path) {
+            Ok(trashed) => {
+                record_last_action(&LastAction::Deleted {
+                    original: project.path.clone(),
+                    trashed,
+                })?;
+                return Ok(true);
+            }
+            Err(e) if permanent && crosses_devices(&e) => {}
+            Err(e) => return Err(e.context(format!("Failed to move {} to the trash", project.path.display()))),
+        }
+
         fs::remove_dir_all(&project.path)
             .with_context(|| format!("Failed to delete project: {}", project.path.display()))?;
+        clear_last_action();
+        Ok(false)
+    }
+
+    fn empty_state_text(&self) -> String {
+        if self.scan.is_some() {
+            return "Scanning…".to_string();
+        }
+        let has_projects = self.all_projects.as_ref().is_some_and(|all| !all.is_empty());
+        if has_projects && !self.input_buffer.is_empty() {
+            format!("No projects match '{}'", self.input_buffer)
+        } else {
+            let roots: Vec<String> = self.roots.iter().map(|r| r.display().to_string()).collect();
+            format!("No projects yet in {}", roots.join(", "))
+        }
+    }
+
+    fn announce(&self, line: &str) -> Result<()> {
+        execute!(io::stderr(), Print(line), Print("\r\n"))?;
+        io::stderr().flush()?;
         Ok(())
     }
 
-    fn render_project(&self, project: &Project, is_selected: bool) -> Result<()> {
-        // Project type icon
-        let icon = match project.project_type {
-            ProjectType::Local => "📁",
-            ProjectType::GitRepo => "🌐",
-        };
+    /// Byte offset in `input_buffer` where typing inserts.
+    fn input_cursor_index(&self) -> usize {
+        let chars = self.input_buffer.chars().count();
+        let before = chars - self.input_cursor_back.min(chars);
+        self.input_buffer.char_indices().nth(before).map_or(self.input_buffer.len(), |(i, _)| i)
+    }
+
+    /// The search text with the character under the text cursor in reverse video.
+    fn render_search_input(&self) -> Result<()> {
+        let at = self.input_cursor_index();
+        let (before, rest) = self.input_buffer.split_at(at);
+        let mut rest = rest.chars();
+        let under = rest.next().unwrap_or(' ');
+        execute!(
+            io::stderr(),
+            Print(before),
+            SetAttribute(Attribute::Reverse),
+            Print(under),
+            SetAttribute(Attribute::NoReverse),
+            Print(rest.as_str()),
+        )?;
+        Ok(())
+    }
 
-        execute!(io::stderr(), Print(format!("{} ", icon)))?;
+    fn announce_project_selection(&self, projects: &[Project], create_new_text: &str) -> Result<()> {
+        let total_items = projects.len() + 2;
+        let item = if self.cursor_pos < projects.len() {
+            let project = &projects[self.cursor_pos];
+            let kind = match project.project_type {
+                ProjectType::Local => "[local]",
+                ProjectType::GitRepo => "[git]",
+            };
+            let pin = if self.config.pinned.contains(&project.name) { "pinned " } else { "" };
+            let mark = if self.marked.contains(&project.path) { "marked " } else { "" };
+            let about = project.description.as_ref().map(|d| format!(", {}", d)).unwrap_or_default();
+            format!("{} {}{}{}{}, {}", kind, mark, pin, project.name, about, format_time(&project.last_accessed, &self.config))
+        } else if self.cursor_pos == projects.len() {
+            strip_emoji(create_new_text).trim().to_string()
+        } else {
+            "Configure".to_string()
+        };
 
-        // Project name with better color handling
-        if is_selected {
-            execute!(
-                io::stderr(),
-                SetForegroundColor(Color::Yellow),
-                Print(&project.name),
-                ResetColor,
-            )?;
+        let search = if projects.is_empty() {
+            format!("{}. ", self.empty_state_text())
+        } else if self.input_buffer.is_empty() {
+            String::new()
         } else {
-            execute!(io::stderr(), Print(&project.name))?;
+            format!("Search \"{}\", {} matches. ", self.input_buffer, projects.len())
+        };
+
+        self.announce(&format!("{}{} of {}: {}", search, self.cursor_pos + 1, total_items, item))
+    }
+
+    /// Projects that look like copies of one another - the same name in two
+    /// roots, or two clones of the same remote - noted with what tells them apart.
+    fn duplicate_notes(&mut self) -> HashMap<PathBuf, String> {
+        let mut notes = HashMap::new();
+        let Some(projects) = self.all_projects.as_ref() else {
+            return notes;
+        };
+
+        let mut by_name: HashMap<&str, Vec<&Project>> = HashMap::new();
+        let mut by_remote: HashMap<String, Vec<&Project>> = HashMap::new();
+        for project in projects {
+            by_name.entry(project.name.as_str()).or_default().push(project);
+            if let ProjectType::GitRepo = project.project_type {
+                let remote = self.project_remotes.entry(project.path.clone()).or_insert_with(|| read_origin_url(&project.path));
+                if let Some(remote) = remote {
+                    by_remote.entry(remote.clone()).or_default().push(project);
+                }
+            }
+        }
+
+        for group in by_remote.values().filter(|group| group.len() > 1) {
+            for project in group {
+                let twins: Vec<&str> = group.iter().filter(|p| p.path != project.path).map(|p| p.name.as_str()).collect();
+                notes.insert(project.path.clone(), format!("same repo as {}", twins.join(", ")));
+            }
+        }
+        // Same name can only happen across roots, and the root is what tells them apart;
+        // it goes first so a clone that's also a same-repo twin keeps both notes
+        for group in by_name.values().filter(|group| group.len() > 1) {
+            for project in group {
+                let root = project.root.file_name().map_or_else(|| project.root.display().to_string(), |n| n.to_string_lossy().to_string());
+                let note = notes.entry(project.path.clone()).or_default();
+                *note = if note.is_empty() { format!("in {}", root) } else { format!("in {}, {}", root, note) };
+            }
         }
+        notes
+    }
+
+    fn render_project(&mut self, project: &Project, is_selected: bool, hint: Option<usize>, note: Option<&str>) -> Result<()> {
+        // Alt+N quick-select hint
+        match hint {
+            Some(n) => execute!(io::stderr(), self.paint(Hue::Meta), Print(format!("{} ", n)), ResetColor)?,
+            None => execute!(io::stderr(), Print("  "))?,
+        }
+
+        let pinned = self.config.pinned.contains(&project.name);
+        let marked = self.marked.contains(&project.path);
+        let tokens = self.visible_row_tokens(project);
+        let align = tokens.iter().position(|t| *t == RowToken::Align).unwrap_or(tokens.len());
+        let (left, right) = tokens.split_at(align);
+
+        // Everything on the left except the name, so we know how much room the name gets
+        let mut left_width = if pinned { 2 } else { 0 } + if marked { 2 } else { 0 };
+        for token in left {
+            if *token != RowToken::Name {
+                left_width += match token {
+                    RowToken::Icon => 2,
+                    _ => self.row_token_text(token, project).chars().count(),
+                };
+            }
+        }
+        let meta_text: String = right
+            .iter()
+            .skip(1)
+            .map(|token| self.row_token_text(token, project))
+            .collect();
 
-        // Format metadata
-        let time_text = self.format_relative_time(&project.last_accessed);
-        let score_text = format!("{:.1}", project.score);
-        let meta_text = format!("{}, {}", time_text, score_text);
+        // Marker and hint take 4 columns; long names get cut rather than wrap
+        let name_room = (self.term_width as usize).saturating_sub(5 + left_width).max(1);
+        let name: String = project.name.chars().take(name_room).collect();
+        let meta_width = meta_text.chars().count() + 1;
+        let note = note.map(|note| format!(" ({})", note)).unwrap_or_default();
+
+        // The description only gets the room left between the name and the meta
+        let spare = (self.term_width as usize)
+            .saturating_sub(4 + left_width + name.chars().count() + note.chars().count() + meta_width + 3);
+        let description = match &project.description {
+            Some(text) if spare >= 10 && text.chars().count() > spare => {
+                format!("{}…", text.chars().take(spare - 1).collect::<String>())
+            }
+            Some(text) if spare >= 10 => text.clone(),
+            _ => String::new(),
+        };
+
+        for token in left {
+            if *token != RowToken::Name {
+                execute!(io::stderr(), Print(self.row_token_text(token, project)))?;
+                continue;
+            }
+            if marked {
+                execute!(io::stderr(), self.paint(Hue::Success), Print("✓ "), ResetColor)?;
+            }
+            if pinned {
+                execute!(io::stderr(), self.paint(Hue::Selection), Print("★ "), ResetColor)?;
+            }
+            // Project name with better color handling
+            if is_selected {
+                execute!(
+                    io::stderr(),
+                    self.paint(Hue::Selection),
+                    Print(&name),
+                    ResetColor,
+                )?;
+            } else {
+                execute!(io::stderr(), Print(&name))?;
+            }
+            if !note.is_empty() {
+                execute!(io::stderr(), self.paint(Hue::Warning), Print(&note), ResetColor)?;
+            }
+            if !description.is_empty() {
+                execute!(io::stderr(), Print("  "), self.paint(Hue::Meta), Print(&description), ResetColor)?;
+            }
+        }
 
         // Calculate padding - handle small terminals gracefully
-        let text_width = project.name.len();
-        let meta_width = meta_text.len() + 1;
-        let min_width = 5 + text_width + meta_width;
-        
-        if (self.term_width as usize) >= min_width {
+        let description_width = if description.is_empty() { 0 } else { description.chars().count() + 2 };
+        let text_width = left_width + name.chars().count() + note.chars().count() + description_width;
+        let min_width = 4 + text_width + meta_width;
+
+        if !meta_text.is_empty() && (self.term_width as usize) >= min_width {
             let padding_needed = (self.term_width as usize).saturating_sub(min_width).max(1);
             let padding = " ".repeat(padding_needed);
             execute!(
                 io::stderr(),
                 Print(&padding),
                 Print(" "),
-                SetForegroundColor(Color::DarkGrey),
+                self.paint(Hue::Meta),
                 Print(&meta_text),
                 ResetColor,
             )?;
         }
 
-
         Ok(())
     }
 
-    fn format_relative_time(&self, time: &DateTime<Utc>) -> String {
-        let now = Utc::now();
-        let duration = now.signed_duration_since(*time);
-        
-        let seconds = duration.num_seconds();
-        let minutes = seconds / 60;
-        let hours = minutes / 60;
-        let days = hours / 24;
-
-        if seconds < 10 {
-            "just now".to_string()
-        } else if minutes < 60 {
-            format!("{}m", minutes)
-        } else if hours < 24 {
-            format!("{}h", hours)
-        } else if days < 30 {
-            format!("{}d", days)
-        } else if days < 365 {
-            format!("{}mo", days / 30)
-        } else {
-            format!("{}y", days / 365)
+    /// `row_format` for one project, minus literal text right after a placeholder
+    /// that shows nothing, so `{root}, ` leaves no stray comma with a single root.
+    fn visible_row_tokens(&mut self, project: &Project) -> Vec<RowToken> {
+        let mut tokens = Vec::new();
+        let mut after_empty = false;
+        for token in self.row_format.clone() {
+            match token {
+                RowToken::Text(_) if after_empty => {
+                    after_empty = false;
+                    continue;
+                }
+                RowToken::Text(_) | RowToken::Icon | RowToken::Name | RowToken::Align => after_empty = false,
+                _ => after_empty = self.row_token_text(&token, project).is_empty(),
+            }
+            tokens.push(token);
+        }
+        tokens
+    }
+
+    /// Whether any `{size}` is still being worked out, so the list should keep redrawing.
+    fn sizes_pending(&self) -> bool {
+        self.project_sizes.values().any(Option::is_none)
+    }
+
+    fn row_token_text(&mut self, token: &RowToken, project: &Project) -> String {
+        match token {
+            RowToken::Text(text) => text.clone(),
+            RowToken::Icon => match project.project_type {
+                ProjectType::Local => "📁".to_string(),
+                ProjectType::GitRepo => "🌐".to_string(),
+            },
+            RowToken::Name => project.name.clone(),
+            RowToken::Time => format_time(&project.last_accessed, &self.config),
+            RowToken::Score => format!("{:.1}", project.score),
+            RowToken::Lang => detect_language(&project.path).to_string(),
+            RowToken::Size => {
+                while let Ok((path, size)) = self.sizes_found.try_recv() {
+                    self.project_sizes.insert(path, Some(size));
+                }
+                match self.project_sizes.get(&project.path) {
+                    Some(Some(size)) => format_size(*size),
+                    Some(None) => "…".to_string(),
+                    None => {
+                        // Walking a big project takes a while, so it happens off the render thread
+                        self.project_sizes.insert(project.path.clone(), None);
+                        let path = project.path.clone();
+                        let ignore_dirs = self.config.ignore_dirs.clone();
+                        let sender = self.size_sender.clone();
+                        thread::spawn(move || {
+                            let mut total = 0;
+                            walk_dir(&path, 8, &ignore_dirs, &mut |entry| {
+                                if let Ok(metadata) = entry.metadata() {
+                                    if metadata.is_file() {
+                                        total += metadata.len();
+                                    }
+                                }
+                            });
+                            let _ = sender.send((path, total));
+                        });
+                        "…".to_string()
+                    }
+                }
+            }
+            // Only worth showing when several roots are mixed together
+            RowToken::Root if self.roots.len() > 1 => {
+                let root_name = project
+                    .root
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_else(|| project.root.display().to_string());
+                root_name
+            }
+            RowToken::Root | RowToken::Align => String::new(),
         }
     }
 
     fn handle_project_selection(&mut self, project: &Project) {
+        // With projects marked, Enter opens them all (plus the highlighted one)
+        let mut workspace = self.marked.clone();
+        if !workspace.is_empty() && !workspace.contains(&project.path) {
+            workspace.push(project.path.clone());
+        }
         self.selected = Some(SelectionResult {
             action: SelectionAction::OpenExisting,
             path: project.path.clone(),
             template: None,
             git_url: None,
+            sparse_path: None,
+            subdir: None,
+            git_ref: None,
+            open: true,
+            workspace,
         });
     }
 
     fn handle_create_new(&mut self) -> Result<()> {
         self.mode = SelectorMode::TemplateSelection;
-        self.cursor_pos = 0;
+        self.template_filter = None;
+        self.scroll_offset = 0;
+        // Start on whatever was picked last time
+        self.cursor_pos = self
+            .last_template
+            .as_ref()
+            .and_then(|last| ProjectTemplate::get_all().iter().position(|t| t == last))
+            .unwrap_or(0);
         Ok(())
     }
 
-    fn handle_clone_repo(&mut self) -> Result<()> {
-        let url = self.normalize_github_url(&self.input_buffer);
+    /// With `ask_name`, a clone whose folder is taken (or every clone, with
+    /// `prompt_clone_name`) first asks what to call the folder.
+    fn handle_clone_repo(&mut self, open: bool, ask_name: bool) -> Result<()> {
+        let target = self.config.aliases.get(&self.input_buffer).unwrap_or(&self.input_buffer);
+        let (target, sparse_path) = split_sparse_path(target);
+        let (url, subdir, git_ref) = split_repo_subdir(&self.normalize_github_url(target));
         let repo_name = self.extract_repo_name(&url);
         let project_path = self.base_path.join(&repo_name);
-        
-        self.selected = Some(SelectionResult {
+        let result = SelectionResult {
             action: SelectionAction::CloneRepo,
             path: project_path,
             template: None,
             git_url: Some(url),
-        });
-        
+            sparse_path: sparse_path.map(str::to_string),
+            subdir,
+            git_ref,
+            open,
+            workspace: Vec::new(),
+        };
+
+        if ask_name && (self.config.prompt_clone_name || result.path.exists()) {
+            let query = std::mem::replace(&mut self.input_buffer, repo_name);
+            self.input_cursor_back = 0;
+            self.clone_pending = Some((result, query));
+            self.status_message = None;
+            self.mode = SelectorMode::CloneName;
+            return Ok(());
+        }
+        self.finish_selection(result);
+
         Ok(())
     }
 
     fn handle_template_selection(&mut self, template: ProjectTemplate) -> Result<()> {
         let project_name = if self.input_buffer.is_empty() {
-            // If no name was entered, use a default name
-            "new-project".to_string()
+            // If no name was entered, use the configured default name
+            resolve_default_project_name(&self.config, &self.base_path)
         } else {
             self.input_buffer.clone()
         };
 
-        let project_path = self.base_path.join(&project_name.replace(' ', "-"));
-        
-        self.selected = Some(SelectionResult {
+        let project_path = self.base_path.join(project_name.replace(' ', "-"));
+        let result = SelectionResult {
             action: SelectionAction::CreateNew,
             path: project_path,
             template: Some(template),
             git_url: None,
-        });
-        
+            sparse_path: None,
+            subdir: None,
+            git_ref: None,
+            open: true,
+            workspace: Vec::new(),
+        };
+        self.finish_selection(result);
         Ok(())
     }
 
+    /// Hand back a create or clone, first asking which root it lands in when
+    /// there are several.
+    fn finish_selection(&mut self, result: SelectionResult) {
+        if self.pick_destination && self.roots.len() > 1 {
+            // Start on wherever this kind of project went last time
+            self.cursor_pos = load_last_destination(&result.action)
+                .and_then(|root| self.roots.iter().position(|r| *r == root))
+                .unwrap_or(0);
+            self.pending_destination = Some(result);
+            self.mode = SelectorMode::PickingDestination;
+            return;
+        }
+        self.place_selection(result);
+    }
+
+    fn place_selection(&mut self, result: SelectionResult) {
+        // Never scaffold over an existing folder; ask what to do instead
+        if matches!(result.action, SelectionAction::CreateNew) && result.path.exists() {
+            self.name_collision = Some(result);
+            self.mode = SelectorMode::ConfirmCollision;
+            return;
+        }
+        self.selected = Some(result);
+    }
+
     fn normalize_github_url(&self, input: &str) -> String {
         if input.starts_with("http") {
             input.to_string()
@@ -1194,38 +3527,193 @@ impl VibeSelector {
         }
         
         // Fallback: extract from the end
-        url.split('/').last().unwrap_or("unknown-repo").trim_end_matches(".git").to_string()
+        url.split('/').next_back().unwrap_or("unknown-repo").trim_end_matches(".git").to_string()
+    }
+
+}
+
+/// Pasted text minus what can't be part of a single-line name or URL
+/// (newlines, tabs and other control characters).
+fn sanitize_paste(text: &str) -> String {
+    text.trim().chars().filter(|ch| !ch.is_control()).collect()
+}
+
+/// Characters the navigator's search box accepts (names plus URL punctuation).
+fn is_query_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '-' || ch == '_' || ch == '.' || ch == ' ' || ch == '/' || ch == ':'
+}
+
+const DEFAULT_PROJECT_NAME: &str = "new-project";
+
+fn is_valid_strftime(pattern: &str) -> bool {
+    !StrftimeItems::new(pattern).any(|item| matches!(item, Item::Error))
+}
+
+/// `default_project_name` with its strftime tokens filled in, falling back to
+/// `new-project` if that isn't usable, and suffixed `-2`, `-3`... until it's free.
+fn resolve_default_project_name(config: &VibeConfig, base_path: &Path) -> String {
+    let pattern = &config.default_project_name;
+    let name = if is_valid_strftime(pattern) {
+        format_timestamp(&Utc::now(), config.local_time, pattern).replace(' ', "-")
+    } else {
+        pattern.replace(' ', "-")
+    };
+    let name = if validate_project_name(&name).is_ok() { name } else { DEFAULT_PROJECT_NAME.to_string() };
+
+    let path = base_path.join(&name);
+    if !path.exists() {
+        return name;
+    }
+    suffixed_project_path(&path).file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or(name)
+}
+
+/// First free `<path>-2`, `<path>-3`, ... next to an existing project.
+fn suffixed_project_path(path: &Path) -> PathBuf {
+    let name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    (2..)
+        .map(|n| path.with_file_name(format!("{}-{}", name, n)))
+        .find(|candidate| !candidate.exists())
+        .unwrap()
+}
+
+/// `My_Awkward.Repo` -> `my-awkward-repo`: lowercase, with runs of
+/// separators collapsed into single dashes.
+fn tidy_project_name(name: &str) -> String {
+    let mut tidy = String::new();
+    for ch in name.chars() {
+        if ch.is_alphanumeric() {
+            tidy.extend(ch.to_lowercase());
+        } else if !tidy.is_empty() && !tidy.ends_with('-') {
+            tidy.push('-');
+        }
+    }
+    tidy.trim_end_matches('-').to_string()
+}
+
+fn validate_project_name(name: &str) -> Result<()> {
+    if name.is_empty() {
+        return Err(anyhow::anyhow!("Project name can't be empty"));
     }
+    if name == "." || name == ".." || name.contains('/') || name.contains('\\') {
+        return Err(anyhow::anyhow!("'{}' is not a valid project name", name));
+    }
+    Ok(())
+}
+
+fn copy_dir_recursive(source: &Path, destination: &Path, skip: &[&str]) -> Result<()> {
+    fs::create_dir_all(destination)?;
+    for entry in fs::read_dir(source)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        if skip.iter().any(|s| name.as_os_str() == *s) {
+            continue;
+        }
 
+        let target = destination.join(&name);
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            copy_dir_recursive(&entry.path(), &target, skip)?;
+        } else if file_type.is_symlink() {
+            #[cfg(unix)]
+            std::os::unix::fs::symlink(fs::read_link(entry.path())?, &target)?;
+            #[cfg(not(unix))]
+            fs::copy(entry.path(), &target).map(|_| ())?;
+        } else {
+            fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
 }
 
 fn get_default_projects_path() -> PathBuf {
-    // Check environment variable first
+    get_default_projects_roots().remove(0)
+}
+
+/// All project roots to browse, primary (where new projects go) first.
+fn get_default_projects_roots() -> Vec<PathBuf> {
+    // Check environment variable first (colon-separated list)
     if let Ok(projects_path) = env::var("slop_PATH") {
-        return PathBuf::from(projects_path);
+        let roots: Vec<PathBuf> = env::split_paths(&projects_path)
+            .filter(|p| !p.as_os_str().is_empty())
+            .collect();
+        if !roots.is_empty() {
+            return roots;
+        }
     }
     
     // Check config file
     if let Ok(config_path) = get_config_file_path() {
         if let Ok(config) = load_config(&config_path) {
             if !config.projects_path.as_os_str().is_empty() {
-                return config.projects_path;
+                let mut roots = vec![config.projects_path];
+                for extra in config.projects_paths {
+                    if !roots.contains(&extra) {
+                        roots.push(extra);
+                    }
+                }
+                return roots;
             }
         }
     }
     
     // Default fallback
     if let Some(home) = home_dir() {
-        home.join("src").join("slop")
+        vec![home.join("src").join("slop")]
     } else {
-        PathBuf::from("slop")
+        vec![PathBuf::from("slop")]
     }
 }
 
+const DEFAULT_DEVCONTAINER_IMAGE: &str = "mcr.microsoft.com/devcontainers/base:ubuntu";
+
+const DEFAULT_ROW_FORMAT: &str = "{icon} {name}|{root}, {time}, {score}";
+
+/// The default before `{root}` stopped adding its own comma; saved configs still carry it.
+const OLD_DEFAULT_ROW_FORMAT: &str = "{icon} {name}|{root}{time}, {score}";
+
+const DEFAULT_IGNORE_DIRS: &[&str] = &["node_modules", "target", ".git", "dist", "vendor"];
+
 #[derive(Debug, Clone)]
 struct VibeConfig {
     projects_path: PathBuf,
+    projects_paths: Vec<PathBuf>,
     default_editor: String,
+    /// Editors Ctrl+E cycles through in the navigator
+    favorite_editors: Vec<String>,
+    /// Editors started alongside default_editor on every open
+    companion_editors: Vec<String>,
+    quiet: bool,
+    accessible: bool,
+    deep_mtime: bool,
+    local_time: bool,
+    time_format: TimeFormat,
+    resume_count: usize,
+    row_format: String,
+    scan_depth: usize,
+    discover_repos: bool,
+    discover_depth: usize,
+    index_ttl: u64,
+    pinned: Vec<String>,
+    copy_env_example: bool,
+    recurse_submodules: bool,
+    sparse_subdir: bool,
+    background_editor: bool,
+    run_on_open: bool,
+    clone_protocol: Option<CloneProtocol>,
+    after_clone: AfterClone,
+    prompt_clone_name: bool,
+    remember_template: bool,
+    theme: Theme,
+    devcontainer_image: String,
+    default_template: ProjectTemplate,
+    default_project_name: String,
+    ignore_dirs: Vec<String>,
+    aliases: BTreeMap<String, String>,
+    /// URL prefix -> mirror prefix, tried before the original host when cloning
+    mirrors: BTreeMap<String, String>,
+    template_dependencies: BTreeMap<String, Vec<String>>,
+    keymap: BTreeMap<String, Vec<String>>,
+    activity_log: Option<PathBuf>,
 }
 
 impl Default for VibeConfig {
@@ -1238,8 +3726,75 @@ impl Default for VibeConfig {
         
         Self {
             projects_path: default_path,
+            projects_paths: Vec::new(),
             default_editor: "claude".to_string(),
+            favorite_editors: Vec::new(),
+            companion_editors: Vec::new(),
+            quiet: false,
+            accessible: false,
+            deep_mtime: false,
+            local_time: false,
+            time_format: TimeFormat::Relative,
+            resume_count: 3,
+            row_format: DEFAULT_ROW_FORMAT.to_string(),
+            scan_depth: 1,
+            discover_repos: false,
+            discover_depth: 4,
+            index_ttl: 300,
+            pinned: Vec::new(),
+            copy_env_example: false,
+            recurse_submodules: false,
+            sparse_subdir: false,
+            background_editor: false,
+            run_on_open: false,
+            clone_protocol: None,
+            after_clone: AfterClone::Ask,
+            prompt_clone_name: false,
+            remember_template: true,
+            theme: Theme::Dark,
+            devcontainer_image: DEFAULT_DEVCONTAINER_IMAGE.to_string(),
+            default_template: ProjectTemplate::Blank,
+            default_project_name: DEFAULT_PROJECT_NAME.to_string(),
+            ignore_dirs: DEFAULT_IGNORE_DIRS.iter().map(|d| d.to_string()).collect(),
+            aliases: BTreeMap::new(),
+            mirrors: BTreeMap::new(),
+            template_dependencies: BTreeMap::new(),
+            keymap: BTreeMap::new(),
+            activity_log: None,
+        }
+    }
+}
+
+impl VibeConfig {
+    fn scan_options(&self) -> ScanOptions {
+        ScanOptions {
+            deep_mtime_cache: if self.deep_mtime {
+                get_data_dir().ok().map(|dir| dir.join("deep_mtime.tsv"))
+            } else {
+                None
+            },
+            git_cache: get_data_dir().ok().map(|dir| dir.join("git_presence.tsv")),
+            ignore_dirs: self.ignore_dirs.clone(),
+            scan_depth: self.scan_depth,
+            discovery: if self.discover_repos { self.discovery() } else { None },
+        }
+    }
+
+    /// Where the navigator keeps its project index; `None` when `index_ttl` is 0.
+    fn index_path(&self) -> Option<PathBuf> {
+        if self.index_ttl == 0 {
+            return None;
         }
+        get_data_dir().ok().map(|dir| dir.join("index.json"))
+    }
+
+    /// Where `discover_repos` looks (HOME) and caches what it finds.
+    fn discovery(&self) -> Option<Discovery> {
+        Some(Discovery {
+            dir: home_dir()?,
+            max_depth: self.discover_depth,
+            cache: get_data_dir().ok()?.join("discovered_repos.tsv"),
+        })
     }
 }
 
@@ -1251,6 +3806,14 @@ fn get_config_file_path() -> Result<PathBuf> {
     }
 }
 
+fn get_data_dir() -> Result<PathBuf> {
+    if let Some(home) = home_dir() {
+        Ok(home.join(".local").join("share").join("slop"))
+    } else {
+        Err(anyhow::anyhow!("Could not find home directory"))
+    }
+}
+
 fn load_config(config_path: &PathBuf) -> Result<VibeConfig> {
     if !config_path.exists() {
         return Ok(VibeConfig::default());
@@ -1260,90 +3823,849 @@ fn load_config(config_path: &PathBuf) -> Result<VibeConfig> {
     let mut config = VibeConfig::default();
     
     // Simple TOML-like parsing (we could use a proper TOML crate, but keeping dependencies minimal)
+    let mut section = String::new();
     for line in content.lines() {
         let line = line.trim();
         if line.is_empty() || line.starts_with('#') {
             continue;
         }
+
+        if line.starts_with('[') && line.ends_with(']') {
+            section = line.trim_matches(|c| c == '[' || c == ']').trim().to_string();
+            continue;
+        }
         
         if let Some((key, value)) = line.split_once('=') {
             let key = key.trim();
-            let value = value.trim().trim_matches('"');
+            let value = unquote_toml(value.trim());
+            let value = value.as_str();
+
+            if section == "aliases" {
+                config.aliases.insert(key.trim_matches('"').to_string(), value.to_string());
+                continue;
+            }
+            if section == "mirrors" {
+                config.mirrors.insert(key.trim_matches('"').to_string(), value.to_string());
+                continue;
+            }
+            if section == "dependencies" {
+                config
+                    .template_dependencies
+                    .insert(key.trim_matches('"').to_lowercase(), parse_string_list(value));
+                continue;
+            }
+            if section == "keymap" {
+                config.keymap.insert(key.to_lowercase(), parse_string_list(value));
+                continue;
+            }
             
             match key {
                 "projects_path" => {
                     config.projects_path = PathBuf::from(value);
                 }
+                "projects_paths" => {
+                    config.projects_paths = parse_string_list(value).into_iter().map(PathBuf::from).collect();
+                }
                 "default_editor" => {
                     config.default_editor = value.to_string();
                 }
-                _ => {} // Ignore unknown keys
-            }
-        }
-    }
-    
-    Ok(config)
-}
-
-fn save_config(config: &VibeConfig) -> Result<()> {
-    let config_path = get_config_file_path()?;
-    
-    // Create config directory if it doesn't exist
-    if let Some(parent) = config_path.parent() {
-        fs::create_dir_all(parent)?;
-    }
-    
-    let content = format!(
-        r#"# slop Configuration
-# Path where projects are stored
-projects_path = "{}"
+                "favorite_editors" => {
+                    config.favorite_editors = parse_string_list(value);
+                }
+                "companion_editors" => {
+                    config.companion_editors = parse_string_list(value);
+                }
+                "quiet" => {
+                    config.quiet = value == "true";
+                }
+                "accessible" => {
+                    config.accessible = value == "true";
+                }
+                "deep_mtime" => {
+                    config.deep_mtime = value == "true";
+                }
+                "timezone" => {
+                    config.local_time = value == "local";
+                }
+                // Older configs: `absolute_times = true` is time_format = "absolute"
+                "absolute_times" if value == "true" && config.time_format == TimeFormat::Relative => {
+                    config.time_format = TimeFormat::Absolute(ABSOLUTE_TIME_FORMAT.to_string());
+                }
+                "time_format" => match TimeFormat::from_name(value) {
+                    Some(format) => config.time_format = format,
+                    None => {
+                        say_err!(
+                            "⚠️  Unknown time_format \"{}\" in {} (expected relative, verbose, absolute, iso or a strftime pattern), using relative times",
+                            value,
+                            config_path.display()
+                        );
+                    }
+                },
+                "ignore_dirs" => {
+                    config.ignore_dirs = parse_string_list(value);
+                }
+                "resume_count" => {
+                    if let Ok(count) = value.parse() {
+                        config.resume_count = count;
+                    }
+                }
+                "copy_env_example" => {
+                    config.copy_env_example = value == "true";
+                }
+                "recurse_submodules" => {
+                    config.recurse_submodules = value == "true";
+                }
+                "sparse_subdir" => {
+                    config.sparse_subdir = value == "true";
+                }
+                "background_editor" => {
+                    config.background_editor = value == "true";
+                }
+                "run_on_open" => {
+                    config.run_on_open = value == "true";
+                }
+                "clone_protocol" => match CloneProtocol::from_name(value) {
+                    Some(protocol) => config.clone_protocol = Some(protocol),
+                    None if value.is_empty() => config.clone_protocol = None,
+                    None => {
+                        say_err!(
+                            "⚠️  Unknown clone_protocol \"{}\" in {} (expected ssh, https, ssh-then-https or https-then-ssh), cloning URLs as given",
+                            value,
+                            config_path.display()
+                        );
+                    }
+                },
+                "after_clone" => match AfterClone::from_name(value) {
+                    Some(choice) => config.after_clone = choice,
+                    None => {
+                        say_err!(
+                            "⚠️  Unknown after_clone \"{}\" in {} (expected ask, editor, shell or done), asking after each clone",
+                            value,
+                            config_path.display()
+                        );
+                    }
+                },
+                "row_format" => {
+                    config.row_format = if value == OLD_DEFAULT_ROW_FORMAT {
+                        DEFAULT_ROW_FORMAT.to_string()
+                    } else {
+                        value.to_string()
+                    };
+                }
+                "scan_depth" => {
+                    if let Ok(depth) = value.parse::<usize>() {
+                        config.scan_depth = depth.max(1);
+                    }
+                }
+                "discover_repos" => {
+                    config.discover_repos = value == "true";
+                }
+                "discover_depth" => {
+                    if let Ok(depth) = value.parse::<usize>() {
+                        config.discover_depth = depth.max(1);
+                    }
+                }
+                "index_ttl" => {
+                    if let Ok(secs) = value.parse() {
+                        config.index_ttl = secs;
+                    }
+                }
+                "pinned" => {
+                    config.pinned = parse_string_list(value);
+                }
+                "prompt_clone_name" => {
+                    config.prompt_clone_name = value == "true";
+                }
+                "remember_template" => {
+                    config.remember_template = value == "true";
+                }
+                "theme" => match Theme::from_name(value) {
+                    Some(theme) => config.theme = theme,
+                    None => {
+                        say_err!(
+                            "⚠️  Unknown theme \"{}\" in {} (expected dark, light or mono), using dark",
+                            value,
+                            config_path.display()
+                        );
+                    }
+                },
+                "devcontainer_image" => {
+                    config.devcontainer_image = value.to_string();
+                }
+                "activity_log" => {
+                    config.activity_log = (!value.is_empty()).then(|| PathBuf::from(value));
+                }
+                "default_project_name" => {
+                    config.default_project_name = value.to_string();
+                }
+                "default_template" => match ProjectTemplate::from_name(value) {
+                    Some(template) => config.default_template = template,
+                    None => {
+                        let names: Vec<&str> = ProjectTemplate::get_all().iter().map(|t| t.display_name()).collect();
+                        say_err!(
+                            "⚠️  Unknown default_template \"{}\" in {} (expected one of: {}), using Blank",
+                            value,
+                            config_path.display(),
+                            names.join(", ")
+                        );
+                    }
+                },
+                _ => {} // Ignore unknown keys
+            }
+        }
+    }
+    
+    Ok(config)
+}
+
+const BOOL_CONFIG_KEYS: &[&str] = &[
+    "quiet",
+    "accessible",
+    "deep_mtime",
+    "absolute_times",
+    "copy_env_example",
+    "discover_repos",
+    "recurse_submodules",
+    "sparse_subdir",
+    "background_editor",
+    "run_on_open",
+    "prompt_clone_name",
+    "remember_template",
+];
+
+const OTHER_CONFIG_KEYS: &[&str] = &[
+    "projects_path",
+    "projects_paths",
+    "default_editor",
+    "favorite_editors",
+    "companion_editors",
+    "timezone",
+    "time_format",
+    "ignore_dirs",
+    "resume_count",
+    "scan_depth",
+    "discover_depth",
+    "index_ttl",
+    "row_format",
+    "pinned",
+    "devcontainer_image",
+    "default_template",
+    "default_project_name",
+    "theme",
+    "clone_protocol",
+    "after_clone",
+    "activity_log",
+];
+
+/// Stricter than `load_config`: reports anything it would silently ignore or fall back on.
+fn check_config_file(config_path: &Path) -> Result<Vec<String>> {
+    let content = fs::read_to_string(config_path)?;
+    let mut problems = Vec::new();
+    let mut section = String::new();
+
+    for (number, line) in content.lines().enumerate() {
+        let number = number + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if line.starts_with('[') && line.ends_with(']') {
+            section = line.trim_matches(|c| c == '[' || c == ']').trim().to_string();
+            if !["aliases", "mirrors", "dependencies", "keymap"].contains(&section.as_str()) {
+                problems.push(format!("line {}: unknown section [{}]", number, section));
+            }
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            problems.push(format!("line {}: expected `key = value`", number));
+            continue;
+        };
+        let key = key.trim();
+        let value = unquote_toml(value.trim());
+        let value = value.as_str();
+
+        if section == "aliases" {
+            if value.is_empty() {
+                problems.push(format!("line {}: alias `{}` has no URL", number, key));
+            }
+            continue;
+        }
+        if section == "mirrors" {
+            if value.is_empty() {
+                problems.push(format!("line {}: mirror for `{}` has no URL", number, key));
+            }
+            continue;
+        }
+        if section == "dependencies" {
+            if ProjectTemplate::from_name(key.trim_matches('"')).is_none() {
+                problems.push(format!("line {}: unknown template `{}`", number, key));
+            }
+            continue;
+        }
+        if section == "keymap" {
+            if KeyAction::from_name(&key.to_lowercase()).is_none() {
+                problems.push(format!("line {}: unknown action `{}`", number, key));
+            }
+            for spec in parse_string_list(value) {
+                if KeySpec::parse(&spec).is_none() {
+                    problems.push(format!("line {}: can't read key `{}`", number, spec));
+                }
+            }
+            continue;
+        }
+
+        if BOOL_CONFIG_KEYS.contains(&key) {
+            if value != "true" && value != "false" {
+                problems.push(format!("line {}: `{}` should be true or false", number, key));
+            }
+            continue;
+        }
+
+        match key {
+            "timezone" if value != "utc" && value != "local" => {
+                problems.push(format!("line {}: `timezone` should be \"utc\" or \"local\"", number));
+            }
+            "resume_count" | "index_ttl" if value.parse::<usize>().is_err() => {
+                problems.push(format!("line {}: `{}` should be a number", number, key));
+            }
+            "scan_depth" | "discover_depth" if !value.parse::<usize>().is_ok_and(|depth| depth >= 1) => {
+                problems.push(format!("line {}: `{}` should be 1 or more", number, key));
+            }
+            "default_template" if ProjectTemplate::from_name(value).is_none() => {
+                problems.push(format!("line {}: unknown template \"{}\"", number, value));
+            }
+            "default_project_name" if !is_valid_strftime(value) || validate_project_name(&value.replace(' ', "-")).is_err() => {
+                problems.push(format!("line {}: `default_project_name` isn't a valid name or strftime pattern", number));
+            }
+            "clone_protocol" if !value.is_empty() && CloneProtocol::from_name(value).is_none() => {
+                problems.push(format!(
+                    "line {}: `clone_protocol` should be \"ssh\", \"https\", \"ssh-then-https\" or \"https-then-ssh\"",
+                    number
+                ));
+            }
+            "time_format" if TimeFormat::from_name(value).is_none() => {
+                problems.push(format!(
+                    "line {}: `time_format` should be \"relative\", \"verbose\", \"absolute\", \"iso\" or a strftime pattern",
+                    number
+                ));
+            }
+            "after_clone" if AfterClone::from_name(value).is_none() => {
+                problems.push(format!("line {}: `after_clone` should be \"ask\", \"editor\", \"shell\" or \"done\"", number));
+            }
+            "theme" if Theme::from_name(value).is_none() => {
+                problems.push(format!("line {}: `theme` should be \"dark\", \"light\" or \"mono\"", number));
+            }
+            _ if !OTHER_CONFIG_KEYS.contains(&key) => {
+                problems.push(format!("line {}: unknown key `{}`", number, key));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(problems)
+}
+
+/// $VISUAL/$EDITOR win; the project editor only if it can edit a file.
+fn config_file_editor(config: &VibeConfig) -> String {
+    for var in ["VISUAL", "EDITOR"] {
+        if let Ok(editor) = env::var(var) {
+            if !editor.trim().is_empty() {
+                return editor;
+            }
+        }
+    }
+    if config.default_editor != "claude" && !config.default_editor.trim().is_empty() {
+        return config.default_editor.clone();
+    }
+    "vi".to_string()
+}
+
+fn parse_string_list(value: &str) -> Vec<String> {
+    value
+        .trim()
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split(',')
+        .map(|item| unquote_toml(item.trim()))
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+/// Escapes `"` and `\` so a value can sit between double quotes in the config file.
+fn escape_toml(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Strips one pair of surrounding double quotes and undoes `escape_toml`.
+/// Other backslashes are kept as-is, so hand-written Windows paths still load.
+fn unquote_toml(value: &str) -> String {
+    let Some(inner) = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) else {
+        return value.to_string();
+    };
+    let mut unquoted = String::with_capacity(inner.len());
+    let mut chars = inner.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            if let Some(&next) = chars.peek().filter(|&&next| next == '"' || next == '\\') {
+                unquoted.push(next);
+                chars.next();
+                continue;
+            }
+        }
+        unquoted.push(c);
+    }
+    unquoted
+}
+
+fn format_string_list<S: AsRef<str>>(items: &[S]) -> String {
+    let quoted: Vec<String> = items.iter().map(|item| format!("\"{}\"", escape_toml(item.as_ref()))).collect();
+    format!("[{}]", quoted.join(", "))
+}
+
+fn save_config(config: &VibeConfig) -> Result<()> {
+    let config_path = get_config_file_path()?;
+    
+    // Create config directory if it doesn't exist
+    if let Some(parent) = config_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    
+    fs::write(&config_path, config_toml(config))?;
+    Ok(())
+}
+
+/// The config file's contents for `config`, comments included.
+fn config_toml(config: &VibeConfig) -> String {
+    let mut content = format!(
+        r#"# slop Configuration
+# Path where projects are stored (new projects are created here)
+projects_path = "{}"
+
+# Additional project roots to browse alongside projects_path
+projects_paths = {}
 
 # Default editor to open projects (cursor, code, etc.)
 default_editor = "{}"
+
+# Editors Ctrl+E switches between in the navigator, e.g. ["claude", "cursor"]
+favorite_editors = {}
+
+# Editors opened together with default_editor, e.g. ["cursor"] to have a GUI editor
+# next to claude; default_editor still starts last and decides whether slop waits
+# (terminal editors here need tmux and get a window of their own)
+companion_editors = {}
+
+# Only print errors when cloning/opening (same as `slop run --quiet`)
+quiet = {}
+
+# Screen-reader friendly navigator: plain text lines, no alternate screen
+accessible = {}
+
+# Rank by the newest file inside each project, not just the folder (costs extra IO)
+deep_mtime = {}
+
+# Timezone for note timestamps and absolute times ("utc" or "local")
+timezone = "{}"
+
+# How the navigator shows times: "relative" (3d), "verbose" (3 days ago), "absolute" (2024-05-01 14:03),
+# "iso" (2024-05-01T14:03:00Z) or your own strftime pattern like "%d %b %H:%M"
+time_format = "{}"
+
+# How many recent projects `slop resume` opens
+resume_count = {}
+
+# Navigator rows: {{icon}} {{name}} {{time}} {{score}} {{size}} {{lang}} {{root}}; text after | is right-aligned
+row_format = "{}"
+
+# How deep to look for projects below each root (1 = direct children only).
+# Deeper levels stop at folders with a Cargo.toml, .git, package.json, README.md, etc.
+scan_depth = {}
+
+# Also list git repos found anywhere under your home folder, up to discover_depth
+# levels deep. The list is cached; `slop reindex` refreshes it.
+discover_repos = {}
+discover_depth = {}
+
+# The navigator lists projects from its last scan right away and rescans in the
+# background once that list is older than this many seconds (0 = always scan first)
+index_ttl = {}
+
+# Projects always listed first (toggle with * in the navigator)
+pinned = {}
+
+# Create .env from .env.example (or env.sample) when opening a project that lacks one (off by default)
+copy_env_example = {}
+
+# Clone with --recurse-submodules so submodule folders aren't left empty
+recurse_submodules = {}
+
+# Check out only the folder named in user/repo/path (or --subdir) instead of the whole repo,
+# for huge monorepos (same as `slop run --sparse`; needs git 2.25+, older ones clone everything)
+sparse_subdir = {}
+
+# Return to the navigator as soon as a GUI editor starts instead of when it closes,
+# so several projects can be opened in a row (terminal editors still take over)
+background_editor = {}
+
+# Start the project's dev command (`npm run dev`, `cargo run`, `make dev`, or
+# `run = "..."` in its .slop file) next to the editor: in a tmux pane inside tmux,
+# otherwise in the background with its output in ~/.local/share/slop/logs
+run_on_open = {}
+
+# Rewrite clone URLs to "ssh" or "https", or try one and fall back to the other on
+# an auth or network failure ("ssh-then-https", "https-then-ssh"); "" = as typed
+clone_protocol = "{}"
+
+# After cloning from the navigator: "ask" (editor, shell or done), or always "editor",
+# "shell" (a shell in the new repo, to look around before running anything) or "done"
+after_clone = "{}"
+
+# Ask what to call the folder before every clone from the navigator, starting from
+# the repo's name (Tab tidies My_Repo.Name into my-repo-name); a taken name always asks
+prompt_clone_name = {}
+
+# Start the template picker on the last template you chose
+remember_template = {}
+
+# Navigator colors: "dark", "light" (for light terminals) or "mono" (no color; NO_COLOR does the same)
+theme = "{}"
+
+# Base image for the Devcontainer template's Dockerfile
+devcontainer_image = "{}"
+
+# Template used when you type a name and press Enter (Alt+Enter opens the picker)
+default_template = "{}"
+
+# Name used when you create a project without typing one; strftime tokens work (e.g. "proj-%Y%m%d")
+default_project_name = "{}"
+
+# Directories never descended into when scanning inside projects
+ignore_dirs = {}
+
+# Opt-in activity log: a file to append a JSON line to for every open, create
+# and clone (see `slop stats --from-log`). It stays on this machine; "" = off
+activity_log = "{}"
 "#,
-        config.projects_path.display(),
-        config.default_editor
+        escape_toml(&config.projects_path.display().to_string()),
+        format_string_list(
+            &config
+                .projects_paths
+                .iter()
+                .map(|p| p.display().to_string())
+                .collect::<Vec<_>>()
+        ),
+        escape_toml(&config.default_editor),
+        format_string_list(&config.favorite_editors),
+        format_string_list(&config.companion_editors),
+        config.quiet,
+        config.accessible,
+        config.deep_mtime,
+        if config.local_time { "local" } else { "utc" },
+        escape_toml(config.time_format.name()),
+        config.resume_count,
+        escape_toml(&config.row_format),
+        config.scan_depth,
+        config.discover_repos,
+        config.discover_depth,
+        config.index_ttl,
+        format_string_list(&config.pinned),
+        config.copy_env_example,
+        config.recurse_submodules,
+        config.sparse_subdir,
+        config.background_editor,
+        config.run_on_open,
+        config.clone_protocol.map_or("", CloneProtocol::name),
+        config.after_clone.name(),
+        config.prompt_clone_name,
+        config.remember_template,
+        config.theme.name(),
+        escape_toml(&config.devcontainer_image),
+        config.default_template.display_name().to_lowercase(),
+        escape_toml(&config.default_project_name),
+        format_string_list(&config.ignore_dirs),
+        escape_toml(&config.activity_log.as_ref().map(|p| p.display().to_string()).unwrap_or_default())
     );
-    
-    fs::write(&config_path, content)?;
+
+    if !config.aliases.is_empty() {
+        content.push_str("\n# Short names that clone the mapped repo\n[aliases]\n");
+        for (name, url) in &config.aliases {
+            content.push_str(&format!("{} = \"{}\"\n", name, escape_toml(url)));
+        }
+    }
+
+    if !config.mirrors.is_empty() {
+        content.push_str("\n# Clone from a mirror first: URL prefix = mirror prefix (the original is the fallback)\n[mirrors]\n");
+        for (prefix, mirror) in &config.mirrors {
+            content.push_str(&format!("\"{}\" = \"{}\"\n", prefix, escape_toml(mirror)));
+        }
+    }
+
+    if !config.template_dependencies.is_empty() {
+        content.push_str("\n# Dependencies written into new projects' manifests (\"name\" or \"name@version\")\n[dependencies]\n");
+        for (template, dependencies) in &config.template_dependencies {
+            content.push_str(&format!("{} = {}\n", template, format_string_list(dependencies)));
+        }
+    }
+
+    if !config.keymap.is_empty() {
+        content.push_str("\n# Navigator keys per action (up, down, select, delete, config, quit, clear, mark, editor), e.g. [\"ctrl+k\", \"f2\"]\n[keymap]\n");
+        for (action, keys) in &config.keymap {
+            content.push_str(&format!("{} = {}\n", action, format_string_list(keys)));
+        }
+    }
+
+    content
+}
+
+/// Keys actually set in the config file, so `config show --json` can tell file values from defaults.
+fn config_file_keys(config_path: &Path) -> Vec<String> {
+    let Ok(content) = fs::read_to_string(config_path) else {
+        return Vec::new();
+    };
+    let mut keys = Vec::new();
+    let mut section = String::new();
+    for line in content.lines() {
+        let line = line.trim();
+        if line.starts_with('[') && line.ends_with(']') {
+            section = line.trim_matches(|c| c == '[' || c == ']').trim().to_string();
+        } else if let Some((key, _)) = line.split_once('=') {
+            if !line.starts_with('#') {
+                let key = if section.is_empty() { key.trim().to_string() } else { section.clone() };
+                if !keys.contains(&key) {
+                    keys.push(key);
+                }
+            }
+        }
+    }
+    keys
+}
+
+/// Where the project roots come from: "env" (slop_PATH, which replaces the
+/// configured roots entirely), "file" or "default".
+fn projects_roots_source(config_path: &Path) -> &'static str {
+    if env::var("slop_PATH").is_ok_and(|v| env::split_paths(&v).any(|p| !p.as_os_str().is_empty())) {
+        "env"
+    } else if config_file_keys(config_path).iter().any(|k| k == "projects_path" || k == "projects_paths") {
+        "file"
+    } else {
+        "default"
+    }
+}
+
+/// Arguments the shell function hands straight to slop instead of treating as a
+/// search; every subcommand but `run` belongs here.
+const PASSTHROUGH_COMMANDS: &[&str] = &["--help", "-h", "help", "config", "init", "new", "rm", "stats", "resume", "undo", "reindex"];
+
+/// `slop init --shell powershell`: the same pass-through as the POSIX function.
+/// The navigator draws on stderr, which PowerShell leaves on the console for
+/// native commands, so there's no `2>/dev/tty` to translate.
+fn print_powershell_init(script_path: &Path, projects_path: Option<&Path>) {
+    let quote = |path: &Path| format!("'{}'", path.display().to_string().replace('\'', "''"));
+    let path_arg = projects_path.map(|p| format!(" --path {}", quote(p))).unwrap_or_default();
+    let passthrough: Vec<String> = PASSTHROUGH_COMMANDS.iter().map(|command| format!("'{}'", command)).collect();
+    println!(
+        r#"function slop {{
+  $scriptPath = {};
+
+  if ($args.Count -eq 0) {{
+    # No arguments - run interactive mode
+    & $scriptPath run{};
+    return;
+  }}
+  if ($args[0] -in @({})) {{
+    # Pass these commands directly to slop
+    & $scriptPath @args;
+  }} else {{
+    # For everything else, use run command
+    & $scriptPath run{} @args;
+  }}
+}}"#,
+        quote(script_path),
+        path_arg,
+        passthrough.join(", "),
+        path_arg
+    );
+}
+
+/// `slop config show --paths`: each place the projects path can come from, in
+/// the order `get_default_projects_roots` checks them, and which one won.
+fn print_search_order(config_path: &Path) {
+    let source = projects_roots_source(config_path);
+    let used = |name: &str| if source == name { "  ← used" } else { "" };
+
+    say!("Config file:  {} ({})", config_path.display(), if config_path.exists() { "found" } else { "not found" });
+    say!();
+    say!("Projects path, checked in order:");
+    match env::var_os("slop_PATH") {
+        Some(value) if !value.is_empty() => say!("  1. slop_PATH    {}{}", value.to_string_lossy(), used("env")),
+        _ => say!("  1. slop_PATH    unset"),
+    }
+    let file_keys = config_file_keys(config_path);
+    if !config_path.exists() {
+        say!("  2. config file  not found");
+    } else if file_keys.iter().any(|k| k == "projects_path" || k == "projects_paths") {
+        let config = load_config(&config_path.to_path_buf()).unwrap_or_default();
+        let mut paths = vec![config.projects_path.display().to_string()];
+        paths.extend(config.projects_paths.iter().map(|p| p.display().to_string()));
+        say!("  2. config file  {}{}", paths.join(", "), used("file"));
+    } else {
+        say!("  2. config file  no projects_path set");
+    }
+    let default = home_dir().map_or(PathBuf::from("slop"), |home| home.join("src").join("slop"));
+    say!("  3. default      {}{}", default.display(), used("default"));
+    say!();
+
+    let roots: Vec<String> = get_default_projects_roots().iter().map(|p| p.display().to_string()).collect();
+    say!("Resolved:     {}", roots.join(", "));
+}
+
+fn print_config_json(config: &VibeConfig, config_path: &Path) -> Result<()> {
+    let file_keys = config_file_keys(config_path);
+    let source = |key: &str| if file_keys.iter().any(|k| k == key) { "file" } else { "default" };
+    let setting = |key: &str, value: serde_json::Value| serde_json::json!({ "value": value, "source": source(key) });
+
+    let roots: Vec<String> = get_default_projects_roots().iter().map(|p| p.display().to_string()).collect();
+    let roots_source = projects_roots_source(config_path);
+
+    let output = serde_json::json!({
+        "config_file": config_path.display().to_string(),
+        "config_file_exists": config_path.exists(),
+        "roots": { "value": roots, "source": roots_source },
+        "settings": {
+            "projects_path": setting("projects_path", config.projects_path.display().to_string().into()),
+            "projects_paths": setting(
+                "projects_paths",
+                config.projects_paths.iter().map(|p| p.display().to_string()).collect::<Vec<_>>().into()
+            ),
+            "default_editor": setting("default_editor", config.default_editor.clone().into()),
+            "favorite_editors": setting("favorite_editors", config.favorite_editors.clone().into()),
+            "companion_editors": setting("companion_editors", config.companion_editors.clone().into()),
+            "quiet": setting("quiet", config.quiet.into()),
+            "accessible": setting("accessible", config.accessible.into()),
+            "deep_mtime": setting("deep_mtime", config.deep_mtime.into()),
+            "timezone": setting("timezone", if config.local_time { "local" } else { "utc" }.into()),
+            "time_format": setting("time_format", config.time_format.name().into()),
+            "resume_count": setting("resume_count", config.resume_count.into()),
+            "scan_depth": setting("scan_depth", config.scan_depth.into()),
+            "index_ttl": setting("index_ttl", config.index_ttl.into()),
+            "discover_repos": setting("discover_repos", config.discover_repos.into()),
+            "discover_depth": setting("discover_depth", config.discover_depth.into()),
+            "row_format": setting("row_format", config.row_format.clone().into()),
+            "pinned": setting("pinned", config.pinned.clone().into()),
+            "copy_env_example": setting("copy_env_example", config.copy_env_example.into()),
+            "recurse_submodules": setting("recurse_submodules", config.recurse_submodules.into()),
+            "sparse_subdir": setting("sparse_subdir", config.sparse_subdir.into()),
+            "background_editor": setting("background_editor", config.background_editor.into()),
+            "run_on_open": setting("run_on_open", config.run_on_open.into()),
+            "clone_protocol": setting("clone_protocol", config.clone_protocol.map(CloneProtocol::name).into()),
+            "after_clone": setting("after_clone", config.after_clone.name().into()),
+            "prompt_clone_name": setting("prompt_clone_name", config.prompt_clone_name.into()),
+            "remember_template": setting("remember_template", config.remember_template.into()),
+            "theme": setting("theme", config.theme.name().into()),
+            "activity_log": setting("activity_log", config.activity_log.as_ref().map(|p| p.display().to_string()).into()),
+            "devcontainer_image": setting("devcontainer_image", config.devcontainer_image.clone().into()),
+            "default_template": setting(
+                "default_template",
+                config.default_template.display_name().to_lowercase().into()
+            ),
+            "default_project_name": setting("default_project_name", config.default_project_name.clone().into()),
+            "ignore_dirs": setting("ignore_dirs", config.ignore_dirs.clone().into()),
+            "aliases": setting("aliases", serde_json::to_value(&config.aliases)?),
+            "mirrors": setting("mirrors", serde_json::to_value(&config.mirrors)?),
+            "dependencies": setting("dependencies", serde_json::to_value(&config.template_dependencies)?),
+            "keymap": setting("keymap", serde_json::to_value(&config.keymap)?),
+        },
+    });
+    println!("{}", serde_json::to_string_pretty(&output)?);
     Ok(())
 }
 
+/// The effective settings in config-file form, plus a `[resolved]` table for
+/// what comes from outside the file (its location and the roots after slop_PATH).
+fn print_config_toml(config: &VibeConfig, config_path: &Path) {
+    let roots: Vec<String> = get_default_projects_roots().iter().map(|p| p.display().to_string()).collect();
+    print!("{}", config_toml(config));
+    println!("\n# Derived, not read back from the config file\n[resolved]");
+    println!("config_file = \"{}\"", config_path.display());
+    println!("config_file_exists = {}", config_path.exists());
+    println!("roots = {}", format_string_list(&roots));
+}
+
 fn print_global_help() {
     // Load config to show current editor
     let config = load_config(&get_config_file_path().unwrap_or_default()).unwrap_or_default();
     
-    println!("slop - vibecoding at hyperspeed");
-    println!();
-    println!("Create projects, clone repos, and launch into {} instantly", config.default_editor);
-    println!();
-    println!("Setup (add to ~/.zshrc or ~/.bashrc):");
-    println!("  eval \"$(slop init ~/src/slop)\"");
-    println!();
-    println!("Usage:");
-    println!("  slop                             # Browse and create projects");
-    println!("  slop my-cool-app                 # Create or find 'my-cool-app'");
-    println!();
-    println!("🌐 GitHub Integration - Just paste any GitHub URL:");
-    println!("  slop https://github.com/user/repo     # Clone full URL");
-    println!("  slop github.com/user/repo             # Clone without https");
-    println!("  slop user/repo                        # Clone shorthand");
-    println!("  slop openAI/GPT-5                   # Example: clone GPT-5");
-    println!();
-    println!("Configuration:");
-    println!("  slop config show          # Show current settings");
-    println!("  slop config path <PATH>   # Set projects directory");
-    println!("  slop config editor <CMD>  # Set editor command (claude, cursor, code)");
-    println!();
-    println!("Default path: ~/src/slop");
-    println!("Current path: {}", get_default_projects_path().display());
+    say!("slop - vibecoding at hyperspeed");
+    say!();
+    say!("Create projects, clone repos, and launch into {} instantly", config.default_editor);
+    say!();
+    say!("Setup (add to ~/.zshrc or ~/.bashrc):");
+    say!("  eval \"$(slop init ~/src/slop)\"");
+    say!();
+    say!("Usage:");
+    say!("  slop                             # Browse and create projects");
+    say!("  slop my-cool-app                 # Create or find 'my-cool-app'");
+    say!("  slop rm my-cool-app              # Delete 'my-cool-app' (asks first)");
+    say!("  slop stats                       # Summarize your project activity");
+    say!("  slop stats --from-log            # Summarize the activity_log file (local only, never uploaded)");
+    say!("  slop resume 3                    # Reopen your 3 most recent projects");
+    say!("  slop undo                        # Undo the last create, clone or delete");
+    say!("  slop reindex                     # Re-find git repos under HOME, rebuild the index");
+    say!();
+    say!("🌐 GitHub Integration - Just paste any GitHub URL:");
+    say!("  slop https://github.com/user/repo     # Clone full URL");
+    say!("  slop github.com/user/repo             # Clone without https");
+    say!("  slop user/repo                        # Clone shorthand");
+    say!("  slop openAI/GPT-5                   # Example: clone GPT-5");
+    say!();
+    say!("Configuration:");
+    say!("  slop config show          # Show current settings");
+    say!("  slop config path <PATH>   # Set projects directory");
+    say!("  slop config editor <CMD>  # Set editor command (claude, cursor, code)");
+    say!();
+    say!("Default path: ~/src/slop");
+    say!("Current path: {}", get_default_projects_path().display());
     if let Ok(config_path) = get_config_file_path() {
-        println!("Config file:  {}", config_path.display());
+        say!("Config file:  {}", config_path.display());
+    }
+}
+
+/// Configured `[dependencies]` for a template, as (name, version) pairs; `tokio@1` carries a version.
+fn template_dependencies(config: &VibeConfig, template: &ProjectTemplate) -> Vec<(String, Option<String>)> {
+    config
+        .template_dependencies
+        .get(&template.display_name().to_lowercase())
+        .map(|items| {
+            items
+                .iter()
+                .map(|item| match item.rsplit_once('@') {
+                    // A leading @ is an npm scope, not a version
+                    Some((name, version)) if !name.is_empty() => (name.to_string(), Some(version.to_string())),
+                    _ => (item.clone(), None),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// `{}` or an indented JSON object of `"name": "version"` for package.json.
+fn package_json_dependencies(dependencies: &[(String, Option<String>)]) -> String {
+    if dependencies.is_empty() {
+        return "{}".to_string();
     }
+    let entries: Vec<String> = dependencies
+        .iter()
+        .map(|(name, version)| format!("    \"{}\": \"{}\"", name, version.as_deref().unwrap_or("*")))
+        .collect();
+    format!("{{\n{}\n  }}", entries.join(",\n"))
 }
 
-fn create_project_from_template(path: &PathBuf, template: &ProjectTemplate) -> Result<()> {
+fn create_project_from_template(path: &PathBuf, template: &ProjectTemplate, config: &VibeConfig) -> Result<()> {
     fs::create_dir_all(path)?;
+    // Only declared here; resolving them is left to the package manager
+    let dependencies = template_dependencies(config, template);
     
     match template {
         ProjectTemplate::Rust => {
@@ -1355,8 +4677,12 @@ version = "0.1.0"
 edition = "2021"
 
 [dependencies]
-"#,
-                path.file_name().unwrap().to_string_lossy()
+{}"#,
+                path.file_name().unwrap().to_string_lossy(),
+                dependencies
+                    .iter()
+                    .map(|(name, version)| format!("{} = \"{}\"\n", name, version.as_deref().unwrap_or("*")))
+                    .collect::<String>()
             );
             fs::write(path.join("Cargo.toml"), cargo_toml)?;
             
@@ -1369,7 +4695,14 @@ edition = "2021"
         },
         ProjectTemplate::Python => {
             fs::write(path.join("main.py"), "#!/usr/bin/env python3\n\nif __name__ == \"__main__\":\n    print(\"Hello, world!\")\n")?;
-            fs::write(path.join("requirements.txt"), "")?;
+            let requirements: String = dependencies
+                .iter()
+                .map(|(name, version)| match version {
+                    Some(version) => format!("{}=={}\n", name, version),
+                    None => format!("{}\n", name),
+                })
+                .collect();
+            fs::write(path.join("requirements.txt"), requirements)?;
         },
         ProjectTemplate::JavaScript => {
             let package_json = format!(
@@ -1381,10 +4714,11 @@ edition = "2021"
   "scripts": {{
     "start": "node index.js"
   }},
-  "dependencies": {{}}
+  "dependencies": {}
 }}
 "#,
-                path.file_name().unwrap().to_string_lossy()
+                path.file_name().unwrap().to_string_lossy(),
+                package_json_dependencies(&dependencies)
             );
             fs::write(path.join("package.json"), package_json)?;
             fs::write(path.join("index.js"), "console.log('Hello, world!');\n")?;
@@ -1401,6 +4735,7 @@ edition = "2021"
     "start": "node dist/index.js",
     "dev": "ts-node src/index.ts"
   }},
+  "dependencies": {},
   "devDependencies": {{
     "typescript": "^5.0.0",
     "@types/node": "^20.0.0",
@@ -1408,7 +4743,8 @@ edition = "2021"
   }}
 }}
 "#,
-                path.file_name().unwrap().to_string_lossy()
+                path.file_name().unwrap().to_string_lossy(),
+                package_json_dependencies(&dependencies)
             );
             fs::write(path.join("package.json"), package_json)?;
             
@@ -1431,10 +4767,190 @@ edition = "2021"
             fs::write(path.join("src/index.ts"), "console.log('Hello, world!');\n")?;
         },
         ProjectTemplate::Go => {
-            let go_mod = format!("module {}\n\ngo 1.21\n", path.file_name().unwrap().to_string_lossy());
+            // go.mod can't declare a module without a version
+            let requires: String = dependencies
+                .iter()
+                .filter_map(|(name, version)| Some(format!("require {} {}\n", name, version.as_ref()?)))
+                .collect();
+            let go_mod = format!(
+                "module {}\n\ngo 1.21\n{}{}",
+                path.file_name().unwrap().to_string_lossy(),
+                if requires.is_empty() { "" } else { "\n" },
+                requires
+            );
             fs::write(path.join("go.mod"), go_mod)?;
             fs::write(path.join("main.go"), "package main\n\nimport \"fmt\"\n\nfunc main() {\n\tfmt.Println(\"Hello, world!\")\n}\n")?;
         },
+        ProjectTemplate::OCaml => {
+            // opam package names allow letters, digits, '-', '_' and '+'
+            let package_name: String = path
+                .file_name()
+                .unwrap()
+                .to_string_lossy()
+                .to_lowercase()
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() || c == '_' || c == '+' { c } else { '-' })
+                .collect();
+
+            let depends = if dependencies.is_empty() {
+                String::new()
+            } else {
+                let names: Vec<&str> = dependencies.iter().map(|(name, _)| name.as_str()).collect();
+                format!("\n (depends {})", names.join(" "))
+            };
+            let dune_project = format!(
+                "(lang dune 3.0)\n\n(name {0})\n\n(package\n (name {0}){1})\n",
+                package_name, depends
+            );
+            fs::write(path.join("dune-project"), dune_project)?;
+
+            fs::create_dir_all(path.join("bin"))?;
+            fs::write(
+                path.join("bin/dune"),
+                format!("(executable\n (public_name {})\n (name main))\n", package_name),
+            )?;
+            fs::write(path.join("bin/main.ml"), "let () = print_endline \"Hello, world!\"\n")?;
+
+            fs::create_dir_all(path.join("lib"))?;
+        },
+        ProjectTemplate::Gleam => {
+            // Gleam names are lowercase letters, digits and '_', starting with a letter
+            let mut module_name: String = path
+                .file_name()
+                .unwrap()
+                .to_string_lossy()
+                .to_lowercase()
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                .skip_while(|c| !c.is_ascii_lowercase())
+                .collect();
+            if module_name.is_empty() {
+                module_name = "app".to_string();
+            }
+
+            let extra: String = dependencies
+                .iter()
+                .map(|(name, version)| format!("{} = \"{}\"\n", name, version.as_deref().unwrap_or(">= 0.0.0")))
+                .collect();
+            let gleam_toml = format!(
+                "name = \"{}\"\nversion = \"1.0.0\"\n\n[dependencies]\ngleam_stdlib = \">= 0.34.0 and < 2.0.0\"\n{}\n[dev-dependencies]\ngleeunit = \">= 1.0.0 and < 2.0.0\"\n",
+                module_name, extra
+            );
+            fs::write(path.join("gleam.toml"), gleam_toml)?;
+
+            fs::create_dir_all(path.join("src"))?;
+            fs::write(
+                path.join(format!("src/{}.gleam", module_name)),
+                "import gleam/io\n\npub fn main() {\n  io.println(\"Hello, world!\")\n}\n",
+            )?;
+
+            fs::create_dir_all(path.join("test"))?;
+            fs::write(
+                path.join(format!("test/{}_test.gleam", module_name)),
+                "import gleeunit\nimport gleeunit/should\n\npub fn main() {\n  gleeunit.main()\n}\n\npub fn hello_world_test() {\n  1\n  |> should.equal(1)\n}\n",
+            )?;
+        },
+        ProjectTemplate::Dart => {
+            // Dart package names are lowercase letters, digits and '_', not starting with a digit
+            let mut package_name: String = path
+                .file_name()
+                .unwrap()
+                .to_string_lossy()
+                .to_lowercase()
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                .skip_while(|c| c.is_ascii_digit())
+                .collect();
+            if package_name.is_empty() {
+                package_name = "app".to_string();
+            }
+
+            let deps: String = dependencies
+                .iter()
+                .map(|(name, version)| format!("  {}: {}\n", name, version.as_deref().unwrap_or("any")))
+                .collect();
+            let pubspec = format!(
+                "name: {}\ndescription: A new Dart project.\nversion: 0.1.0\npublish_to: none\n\nenvironment:\n  sdk: ^3.0.0\n{}",
+                package_name,
+                if deps.is_empty() { String::new() } else { format!("\ndependencies:\n{}", deps) }
+            );
+            fs::write(path.join("pubspec.yaml"), pubspec)?;
+            fs::write(path.join("analysis_options.yaml"), "analyzer:\n  language:\n    strict-casts: true\n    strict-raw-types: true\n")?;
+
+            fs::create_dir_all(path.join("bin"))?;
+            fs::write(path.join("bin/main.dart"), "void main() {\n  print('Hello, world!');\n}\n")?;
+        },
+        ProjectTemplate::Devcontainer => {
+            let name = path.file_name().unwrap().to_string_lossy();
+            fs::create_dir_all(path.join(".devcontainer"))?;
+
+            let devcontainer_json = format!(
+                r#"{{
+  "name": "{}",
+  "build": {{
+    "dockerfile": "Dockerfile",
+    "context": ".."
+  }},
+  "workspaceFolder": "/workspaces/${{localWorkspaceFolderBasename}}"
+}}
+"#,
+                name
+            );
+            fs::write(path.join(".devcontainer/devcontainer.json"), devcontainer_json)?;
+
+            fs::write(
+                path.join(".devcontainer/Dockerfile"),
+                format!(
+                    "FROM {}\n\n# Install project tooling here, e.g.\n# RUN apt-get update && apt-get install -y --no-install-recommends <packages>\n",
+                    config.devcontainer_image
+                ),
+            )?;
+            fs::write(path.join("README.md"), format!("# {}\n\nOpen in a devcontainer-aware editor to build `.devcontainer/Dockerfile`.\n", name))?;
+        },
+        ProjectTemplate::Docker => {
+            // A tiny Go HTTP service: small multi-stage image, nothing to install locally
+            let go_mod = format!("module {}\n\ngo 1.21\n", path.file_name().unwrap().to_string_lossy());
+            fs::write(path.join("go.mod"), go_mod)?;
+            fs::write(
+                path.join("main.go"),
+                r#"package main
+
+import (
+	"fmt"
+	"log"
+	"net/http"
+)
+
+func main() {
+	http.HandleFunc("/", func(w http.ResponseWriter, r *http.Request) {
+		fmt.Fprintln(w, "Hello, world!")
+	})
+	log.Println("listening on :8080")
+	log.Fatal(http.ListenAndServe(":8080", nil))
+}
+"#,
+            )?;
+            fs::write(
+                path.join("Dockerfile"),
+                r#"FROM golang:1.21-alpine AS build
+WORKDIR /src
+COPY go.mod ./
+RUN go mod download
+COPY . .
+RUN CGO_ENABLED=0 go build -o /out/app .
+
+FROM gcr.io/distroless/static-debian12
+COPY --from=build /out/app /app
+EXPOSE 8080
+ENTRYPOINT ["/app"]
+"#,
+            )?;
+            fs::write(path.join(".dockerignore"), ".git\n.env\nDockerfile\ndocker-compose.yml\n")?;
+            fs::write(
+                path.join("docker-compose.yml"),
+                "services:\n  app:\n    build: .\n    ports:\n      - \"8080:8080\"\n",
+            )?;
+        },
         ProjectTemplate::Blank => {
             // Just create a README
             fs::write(path.join("README.md"), format!("# {}\n\n", path.file_name().unwrap().to_string_lossy()))?;
@@ -1444,144 +4960,1945 @@ edition = "2021"
     Ok(())
 }
 
-fn clone_repository(url: &str, path: &PathBuf) -> Result<()> {
-    let output = Command::new("git")
-        .arg("clone")
-        .arg(url)
-        .arg(path)
-        .output()?;
+/// Split a trailing `:path/to/subdir` off a repo reference. A colon that is
+/// part of a scheme (`https://`) or an SSH host (`git@github.com:user/repo`)
+/// is left alone: the part before the split must already name `owner/repo`.
+fn split_sparse_path(input: &str) -> (&str, Option<&str>) {
+    match input.rsplit_once(':') {
+        Some((repo, subdir)) if repo.contains('/') && !subdir.is_empty() && !subdir.starts_with('/') => {
+            (repo, Some(subdir.trim_end_matches('/')))
+        }
+        _ => (input, None),
+    }
+}
+
+/// Split a monorepo subfolder off a GitHub URL, so `https://github.com/user/repo/pkg/api`
+/// clones `https://github.com/user/repo` and opens `pkg/api`.
+/// Also takes links copied from GitHub's file browser: `tree/<ref>/dir` opens
+/// `dir` on `<ref>`, and `blob/<ref>/dir/file` opens the file's folder. A ref
+/// with a `/` in it can't be told apart from the path, so only its first
+/// segment is taken.
+fn split_repo_subdir(url: &str) -> (String, Option<String>, Option<String>) {
+    if let Ok(parsed) = Url::parse(url) {
+        if parsed.host_str() == Some("github.com") {
+            let parts: Vec<&str> = parsed.path().split('/').filter(|part| !part.is_empty()).collect();
+            if parts.len() > 2 {
+                let repo = format!("{}://github.com/{}/{}", parsed.scheme(), parts[0], parts[1]);
+                let (git_ref, mut path) = match parts[2..] {
+                    ["tree" | "blob", git_ref, ref rest @ ..] => (Some(git_ref.to_string()), rest.to_vec()),
+                    ref rest => (None, rest.to_vec()),
+                };
+                if parts[2] == "blob" {
+                    path.pop();
+                }
+                let subdir = (!path.is_empty()).then(|| path.join("/"));
+                return (repo, subdir, git_ref);
+            }
+        }
+    }
+    (url.to_string(), None, None)
+}
+
+/// Switch a fresh clone to the ref its link pointed at; the default branch
+/// stays checked out (with a warning) if that fails.
+fn checkout_ref(path: &Path, git_ref: Option<&str>, quiet: bool) {
+    let Some(git_ref) = git_ref else {
+        return;
+    };
+    let output = Command::new("git").arg("-C").arg(path).args(["checkout", "-q", git_ref]).output();
+    match output {
+        Ok(output) if output.status.success() => {
+            if !quiet {
+                say!("🔀 Checked out {}", git_ref);
+            }
+        }
+        Ok(output) => say_err!(
+            "⚠️  Could not check out {}, staying on the default branch: {}",
+            git_ref,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ),
+        Err(e) => say_err!("⚠️  Could not check out {}: {}", git_ref, e),
+    }
+}
+
+/// The folder to open in a fresh clone: `subdir` when it's there, otherwise the
+/// repo root with a warning.
+fn clone_open_path(path: &Path, subdir: Option<&str>) -> PathBuf {
+    match subdir {
+        Some(subdir) if path.join(subdir).is_dir() => path.join(subdir),
+        Some(subdir) => {
+            say_err!("⚠️  {} has no {} folder, opening the repo root", path.display(), subdir);
+            path.to_path_buf()
+        }
+        None => path.to_path_buf(),
+    }
+}
+
+/// Which transport clones use, optionally falling back to the other one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CloneProtocol {
+    Ssh,
+    Https,
+    SshThenHttps,
+    HttpsThenSsh,
+}
+
+impl CloneProtocol {
+    fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "ssh" => Some(CloneProtocol::Ssh),
+            "https" => Some(CloneProtocol::Https),
+            "ssh-then-https" => Some(CloneProtocol::SshThenHttps),
+            "https-then-ssh" => Some(CloneProtocol::HttpsThenSsh),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            CloneProtocol::Ssh => "ssh",
+            CloneProtocol::Https => "https",
+            CloneProtocol::SshThenHttps => "ssh-then-https",
+            CloneProtocol::HttpsThenSsh => "https-then-ssh",
+        }
+    }
+
+    /// Whether each attempt goes over SSH, in order.
+    fn attempts(self) -> &'static [bool] {
+        match self {
+            CloneProtocol::Ssh => &[true],
+            CloneProtocol::Https => &[false],
+            CloneProtocol::SshThenHttps => &[true, false],
+            CloneProtocol::HttpsThenSsh => &[false, true],
+        }
+    }
+}
+
+/// What happens once a clone from the navigator finishes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum AfterClone {
+    Ask,
+    Editor,
+    Shell,
+    Done,
+}
+
+impl AfterClone {
+    fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "ask" => Some(AfterClone::Ask),
+            "editor" => Some(AfterClone::Editor),
+            "shell" => Some(AfterClone::Shell),
+            "done" => Some(AfterClone::Done),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            AfterClone::Ask => "ask",
+            AfterClone::Editor => "editor",
+            AfterClone::Shell => "shell",
+            AfterClone::Done => "done",
+        }
+    }
+
+    /// The configured choice, asking on a terminal when it's `Ask`. Without a
+    /// terminal to ask on, the editor opens as it always has.
+    fn resolve(self, config: &VibeConfig) -> Result<Self> {
+        if self != AfterClone::Ask {
+            return Ok(self);
+        }
+        if !io::stdin().is_terminal() {
+            return Ok(AfterClone::Editor);
+        }
+        print!("Open in [e]ditor ({}), a [s]hell here, or [d]one? [E/s/d] ", config.default_editor);
+        io::stdout().flush()?;
+        let mut input = String::new();
+        io::stdin().read_line(&mut input)?;
+        Ok(match input.trim().to_lowercase().as_str() {
+            "s" | "shell" => AfterClone::Shell,
+            "d" | "done" | "n" | "no" => AfterClone::Done,
+            _ => AfterClone::Editor,
+        })
+    }
+}
+
+/// Start the user's shell inside `path` and wait for them to leave it.
+fn open_shell(path: &Path, config: &VibeConfig) -> Result<()> {
+    let shell = env::var("SHELL").unwrap_or_else(|_| "sh".to_string());
+    if !config.quiet {
+        say!("🐚 Opening {} in {} (exit to leave)", shell, path.display());
+    }
+    Command::new(&shell)
+        .current_dir(path)
+        .status()
+        .with_context(|| format!("Failed to start {}", shell))?;
+    Ok(())
+}
+
+/// `url` with the longest matching `[mirrors]` prefix swapped for its mirror,
+/// e.g. `https://github.com/` -> `https://git.corp.example/github/`.
+fn mirror_url(url: &str, mirrors: &BTreeMap<String, String>) -> Option<String> {
+    mirrors
+        .iter()
+        .filter(|(prefix, _)| url.starts_with(prefix.as_str()))
+        .max_by_key(|(prefix, _)| prefix.len())
+        .map(|(prefix, mirror)| format!("{}{}", mirror, &url[prefix.len()..]))
+}
+
+/// `url` rewritten to `git@host:org/repo.git` or `https://host/org/repo`;
+/// anything unrecognized (like a local path) is left alone.
+fn clone_url_for(url: &str, ssh: bool) -> String {
+    let Some(https) = remote_web_url(url) else {
+        return url.to_string();
+    };
+    if !ssh {
+        return https;
+    }
+    let (host, repo_path) = https.trim_start_matches("https://").split_once('/').unwrap_or_default();
+    format!("git@{}:{}.git", host, repo_path)
+}
+
+/// Turn a fresh clone of a template repo into a starter project of its own:
+/// its history goes, `{{project-name}}` placeholders get the new name, and it
+/// gets a new `git init`.
+fn detach_template(path: &Path, name: &str, substitute: bool) -> Result<()> {
+    fs::remove_dir_all(path.join(".git")).with_context(|| format!("Failed to remove {}/.git", path.display()))?;
+
+    if substitute {
+        let mut files = Vec::new();
+        walk_dir(path, 16, &[], &mut |entry| {
+            if entry.file_type().is_ok_and(|t| t.is_file()) {
+                files.push(entry.path());
+            }
+        });
+        for file in files {
+            // Binary files aren't valid UTF-8 and are left alone
+            let Ok(content) = fs::read_to_string(&file) else {
+                continue;
+            };
+            if content.contains("{{project-name}}") || content.contains("{{project_name}}") {
+                let replaced = content.replace("{{project-name}}", name).replace("{{project_name}}", &name.replace('-', "_"));
+                fs::write(&file, replaced)?;
+            }
+        }
+    }
+
+    let output = Command::new("git").arg("-C").arg(path).args(["init", "-q"]).output()?;
+    if !output.status.success() {
+        return Err(anyhow::anyhow!("git init failed: {}", String::from_utf8_lossy(&output.stderr).trim()));
+    }
+    Ok(())
+}
+
+fn clone_repository(
+    url: &str,
+    path: &PathBuf,
+    sparse_path: Option<&str>,
+    quiet: bool,
+    recurse_submodules: bool,
+    protocol: Option<CloneProtocol>,
+    mirrors: &BTreeMap<String, String>,
+) -> Result<()> {
+    ensure_git_installed()?;
+    let mut sparse_path = sparse_path;
+    if sparse_path.is_some() && !git_supports_sparse_checkout() {
+        say_err!("⚠️  Sparse checkout needs git 2.25 or newer, cloning the whole repo");
+        sparse_path = None;
+    }
+
+    // A configured mirror goes first; the repo's own host is the fallback
+    let mut attempts: Vec<(String, Option<bool>)> = mirror_url(url, mirrors).into_iter().map(|m| (m, None)).collect();
+    let mirrored = !attempts.is_empty();
+    match protocol {
+        Some(protocol) => attempts.extend(protocol.attempts().iter().map(|&ssh| (clone_url_for(url, ssh), Some(ssh)))),
+        None => attempts.push((url.to_string(), None)),
+    }
+    let transport = |ssh: bool| if ssh { "SSH" } else { "HTTPS" };
+
+    let mut error = String::new();
+    for (idx, (url, ssh)) in attempts.iter().enumerate() {
+        let from_mirror = mirrored && idx == 0;
+        if from_mirror && !quiet {
+            say!("🪞 Trying mirror {}", url);
+        } else if idx == 1 && mirrored && !quiet {
+            say!("↪️  Mirror failed, cloning from {}", url);
+        } else if idx > 0 && !quiet {
+            say!("↪️  Retrying over {}: {}", transport(ssh.unwrap_or_default()), url);
+        }
+        match git_clone(url, path, sparse_path, quiet, recurse_submodules)? {
+            None => {
+                if !quiet && from_mirror {
+                    say!("✅ Cloned from mirror {}", url);
+                } else if let (Some(ssh), false) = (ssh, quiet) {
+                    say!("✅ Cloned over {}", transport(*ssh));
+                } else if mirrored && !quiet {
+                    say!("✅ Cloned from {}", url);
+                }
+                error.clear();
+                break;
+            }
+            // Only a transport problem is worth retrying the other way; a mirror
+            // may simply not carry this repo, so anything falls back from it
+            Some(stderr) => {
+                let retry = from_mirror || is_auth_failure(&stderr) || is_connection_failure(&stderr);
+                error = stderr;
+                if !retry {
+                    break;
+                }
+            }
+        }
+    }
+
+    if !error.is_empty() {
+        if is_auth_failure(&error) {
+            say_err!();
+            say_err!("🔒 Git couldn't authenticate. For private repos, either:");
+            say_err!("   • export GITHUB_TOKEN=<personal access token> and retry");
+            say_err!("   • set up an SSH key and clone with git@github.com:user/repo.git");
+            say_err!("   • configure a git credential helper (gh auth setup-git)");
+        }
+        return Err(anyhow::anyhow!("Git clone failed: {}", error.trim()));
+    }
+
+    if let Some(subdir) = sparse_path {
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(path)
+            .args(["sparse-checkout", "set", subdir])
+            .output()?;
+        if !output.status.success() {
+            // The clone itself is fine, so check everything out rather than fail
+            say_err!(
+                "⚠️  Sparse checkout of {} failed ({}), checking out the whole repo",
+                subdir,
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+            let disabled = Command::new("git").arg("-C").arg(path).args(["sparse-checkout", "disable"]).status()?;
+            if !disabled.success() {
+                return Err(anyhow::anyhow!("Couldn't check out {} after the sparse checkout failed", path.display()));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Fail with install instructions, rather than a bare "No such file or
+/// directory" from spawning, when git isn't on PATH.
+fn ensure_git_installed() -> Result<()> {
+    match Command::new("git").arg("--version").stdout(Stdio::null()).stderr(Stdio::null()).status() {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            let hint = if cfg!(target_os = "macos") {
+                "run `xcode-select --install` or `brew install git`"
+            } else if cfg!(windows) {
+                "download it from https://git-scm.com/download/win or run `winget install Git.Git`"
+            } else {
+                "install it with your package manager, e.g. `sudo apt install git`"
+            };
+            Err(anyhow::anyhow!("Cloning needs git, which isn't installed (or isn't on PATH): {}", hint))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// `git sparse-checkout` and `git clone --sparse` arrived in git 2.25.
+fn git_supports_sparse_checkout() -> bool {
+    let Ok(output) = Command::new("git").arg("--version").output() else {
+        return false;
+    };
+    // "git version 2.39.2" (Apple's adds " (Apple Git-143)")
+    let version = String::from_utf8_lossy(&output.stdout);
+    let mut numbers = version
+        .split_whitespace()
+        .nth(2)
+        .unwrap_or_default()
+        .split('.')
+        .map(|part| part.parse::<u32>().unwrap_or(0));
+    (numbers.next().unwrap_or(0), numbers.next().unwrap_or(0)) >= (2, 25)
+}
+
+/// One `git clone`; returns git's stderr if it failed.
+fn git_clone(
+    url: &str,
+    path: &PathBuf,
+    sparse_path: Option<&str>,
+    quiet: bool,
+    recurse_submodules: bool,
+) -> Result<Option<String>> {
+    let mut command = Command::new("git");
+
+    // Hand GITHUB_TOKEN to git for this clone only; the helper reads it from the
+    // environment so the token never ends up in the remote URL or .git/config
+    if env::var("GITHUB_TOKEN").is_ok_and(|token| !token.is_empty())
+        && url.starts_with("https://github.com/")
+    {
+        command.arg("-c").arg(
+            "credential.helper=!f() { echo username=x-access-token; echo \"password=$GITHUB_TOKEN\"; }; f",
+        );
+    }
+
+    // Inherit stdin/stdout so git's credential prompts work; stderr is teed so
+    // progress still shows while we keep a copy to diagnose failures
+    let mut child = command
+        .arg("clone")
+        .args(quiet.then_some("--quiet"))
+        .args(recurse_submodules.then_some("--recurse-submodules"))
+        // Sparse clones skip blobs outside the subdirectory until they're needed
+        .args(if sparse_path.is_some() { &["--filter=blob:none", "--sparse"][..] } else { &[] })
+        .arg(url)
+        .arg(path)
+        .stdin(Stdio::inherit())
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    let mut captured = Vec::new();
+    if let Some(mut stderr) = child.stderr.take() {
+        let mut buf = [0u8; 4096];
+        loop {
+            let read = stderr.read(&mut buf)?;
+            if read == 0 {
+                break;
+            }
+            io::stderr().write_all(&buf[..read])?;
+            captured.extend_from_slice(&buf[..read]);
+        }
+    }
+
+    if child.wait()?.success() {
+        Ok(None)
+    } else {
+        Ok(Some(String::from_utf8_lossy(&captured).to_string()))
+    }
+}
+
+fn is_connection_failure(git_stderr: &str) -> bool {
+    let stderr = git_stderr.to_lowercase();
+    [
+        "could not resolve host",
+        "connection refused",
+        "connection timed out",
+        "operation timed out",
+        "network is unreachable",
+        "could not read from remote repository",
+        "failed to connect",
+    ]
+    .iter()
+    .any(|needle| stderr.contains(needle))
+}
+
+fn is_auth_failure(git_stderr: &str) -> bool {
+    let stderr = git_stderr.to_lowercase();
+    [
+        "authentication failed",
+        "could not read username",
+        "permission denied (publickey)",
+        "repository not found",
+        // git's wording, e.g. "The requested URL returned error: 403"
+        "error: 403",
+    ]
+    .iter()
+    .any(|needle| stderr.contains(needle))
+}
+
+fn validate_projects_path(value: &str) -> (Hue, String) {
+    let path = Path::new(value.trim());
+    if value.trim().is_empty() {
+        return (Hue::Error, "✗ invalid: path is empty".to_string());
+    }
+    if !path.is_absolute() {
+        return (Hue::Error, "✗ invalid: use an absolute path".to_string());
+    }
+    if path.is_dir() {
+        return (Hue::Success, "✓ exists".to_string());
+    }
+    if path.exists() {
+        return (Hue::Error, "✗ invalid: not a directory".to_string());
+    }
+
+    // Missing directories are created on launch, as long as the nearest existing ancestor is a directory
+    match path.ancestors().skip(1).find(|ancestor| ancestor.exists()) {
+        Some(ancestor) if ancestor.is_dir() => (Hue::Warning, "• will be created".to_string()),
+        _ => (Hue::Error, "✗ invalid: parent is not a directory".to_string()),
+    }
+}
+
+fn validate_editor_command(value: &str) -> (Hue, String) {
+    let Some(program) = split_command_line(value).into_iter().next() else {
+        return (Hue::Error, "✗ invalid: command is empty".to_string());
+    };
+    match find_in_path(&program) {
+        Some(found) => (Hue::Success, format!("✓ found: {}", found.display())),
+        None => (Hue::Error, format!("✗ {} not found in PATH", program)),
+    }
+}
+
+fn find_in_path(program: &str) -> Option<PathBuf> {
+    if program.contains(std::path::MAIN_SEPARATOR) {
+        let path = PathBuf::from(program);
+        return path.is_file().then_some(path);
+    }
+    env::split_paths(&env::var_os("PATH")?)
+        .map(|dir| dir.join(program))
+        .find(|candidate| candidate.is_file())
+}
+
+const KNOWN_EDITORS: &[&str] = &["claude", "cursor", "code", "zed", "nvim", "vim", "hx", "emacs", "nano"];
+
+/// Known editors that are actually on PATH, in `KNOWN_EDITORS` order.
+fn installed_editors() -> Vec<&'static str> {
+    KNOWN_EDITORS.iter().copied().filter(|e| find_in_path(e).is_some()).collect()
+}
+
+/// The program part of an editor command like `code --wait`.
+fn editor_program(command: &str) -> String {
+    split_command_line(command).into_iter().next().unwrap_or_default()
+}
+
+/// Split a configured command into words the way a shell would, so
+/// `code --wait` or `"/opt/My Editor/bin/edit" -n` run with their arguments.
+fn split_command_line(command: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut in_word = false;
+    let mut quote = None;
+    let mut chars = command.chars();
+    while let Some(ch) = chars.next() {
+        match (quote, ch) {
+            (Some(q), ch) if ch == q => quote = None,
+            (Some('"'), '\\') | (None, '\\') => {
+                if let Some(next) = chars.next() {
+                    word.push(next);
+                }
+                in_word = true;
+            }
+            (Some(_), ch) => word.push(ch),
+            (None, '\'' | '"') => {
+                quote = Some(ch);
+                in_word = true;
+            }
+            (None, ch) if ch.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut word));
+                    in_word = false;
+                }
+            }
+            (None, ch) => {
+                word.push(ch);
+                in_word = true;
+            }
+        }
+    }
+    if in_word {
+        words.push(word);
+    }
+    words
+}
+
+/// A `Command` for an editor setting, with any arguments it carries already added.
+fn editor_command(editor: &str) -> Command {
+    let mut words = split_command_line(editor).into_iter();
+    let mut command = Command::new(words.next().unwrap_or_default());
+    command.args(words);
+    command
+}
+
+/// First run only (no config file yet): pick the editor from what's installed
+/// and save it, instead of silently falling back when `claude` is missing.
+fn first_run_editor_setup(config: &mut VibeConfig) -> Result<()> {
+    let config_path = get_config_file_path()?;
+    if config_path.exists() || !io::stdin().is_terminal() {
+        return Ok(());
+    }
+
+    let installed = installed_editors();
+    let suggested = if installed.contains(&config.default_editor.as_str()) || installed.is_empty() {
+        config.default_editor.clone()
+    } else {
+        installed[0].to_string()
+    };
+
+    say!("👋 Which editor should slop open projects in?");
+    for (i, editor) in installed.iter().enumerate() {
+        say!("  {}. {}", i + 1, editor);
+    }
+    if installed.is_empty() {
+        say!("  (none of {} found in PATH)", KNOWN_EDITORS.join(", "));
+    }
+    print!("Number or command [{}]: ", suggested);
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    let choice = match input.trim() {
+        "" => suggested,
+        answer => match answer.parse::<usize>() {
+            Ok(n) if (1..=installed.len()).contains(&n) => installed[n - 1].to_string(),
+            _ => answer.to_string(),
+        },
+    };
+
+    // Save only the choice; `config` may carry one-off CLI flags like --quiet
+    let saved = VibeConfig {
+        default_editor: choice.clone(),
+        ..VibeConfig::default()
+    };
+    save_config(&saved)?;
+    say!("✅ Saved to {} (change it any time with `slop config editor`)", config_path.display());
+    config.default_editor = choice;
+    Ok(())
+}
+
+/// `config` with default_editor swapped for the editor set for this project
+/// with `E`, when there is one.
+fn with_project_editor(path: &Path, config: &VibeConfig) -> VibeConfig {
+    let mut config = config.clone();
+    if let Some(editor) = load_project_editor(path) {
+        config.default_editor = editor;
+    }
+    config
+}
+
+fn editor_candidates(config: &VibeConfig) -> Vec<&str> {
+    // Try configured editor first, then fallbacks
+    let mut editors_to_try = vec![config.default_editor.as_str()];
+    
+    // Add fallbacks if they're not already the default
+    if config.default_editor != "claude" {
+        editors_to_try.push("claude");
+    }
+    if config.default_editor != "cursor" {
+        editors_to_try.push("cursor");
+    }
+    if config.default_editor != "code" {
+        editors_to_try.push("code");
+    }
+    editors_to_try
+}
+
+const ENV_EXAMPLE_FILES: &[&str] = &[".env.example", ".env.sample", "env.example", "env.sample"];
+
+/// Seed `.env` from the first example file found; never touches an existing `.env`.
+fn copy_env_example(path: &Path) -> Result<Option<&'static str>> {
+    let env_path = path.join(".env");
+    if env_path.symlink_metadata().is_ok() {
+        return Ok(None);
+    }
+
+    for example in ENV_EXAMPLE_FILES {
+        let example_path = path.join(example);
+        if example_path.is_file() {
+            // create_new so a racing writer can't get clobbered either
+            let mut target = fs::OpenOptions::new().write(true).create_new(true).open(&env_path)?;
+            target.write_all(&fs::read(&example_path)?)?;
+            return Ok(Some(example));
+        }
+    }
+
+    Ok(None)
+}
+
+fn prepare_env_file(path: &Path, config: &VibeConfig) {
+    if !config.copy_env_example {
+        return;
+    }
+    match copy_env_example(path) {
+        Ok(Some(example)) => {
+            if !config.quiet {
+                say!("🔐 Created .env from {} - fill in your secrets", example);
+            }
+        }
+        Ok(None) => {}
+        Err(e) => say_err!("⚠️  Could not create .env: {}", e),
+    }
+}
+
+fn open_in_editor(path: &PathBuf, roots: &[PathBuf], config: &VibeConfig) -> Result<()> {
+    prepare_env_file(path, config);
+    let config = &with_project_editor(path, config);
+
+    // The editor starts in the project, but slop's own working directory stays
+    // put so relative roots still resolve for the relaunched navigator
+    if !path.is_dir() {
+        return Err(anyhow::anyhow!("{} is not a directory", path.display()));
+    }
+    if config.run_on_open {
+        start_dev_command(path, config);
+    }
+    for editor in config.companion_editors.iter().filter(|e| **e != config.default_editor) {
+        start_companion_editor(path, editor, config);
+    }
+    
+    let editors_to_try = editor_candidates(config);
+    
+    for editor in &editors_to_try {
+        let program = editor_program(editor);
+        let mut command = editor_command(editor);
+        command.current_dir(path);
+        // Claude doesn't need a path argument - it opens in current directory
+        if program != "claude" {
+            command.arg(".");
+        }
+        // Terminal editors need the terminal to themselves, so only GUI ones run alongside the navigator
+        let background = config.background_editor && !TERMINAL_EDITORS.contains(&program.as_str());
+        if background {
+            command.stdin(Stdio::null()).stdout(Stdio::null()).stderr(Stdio::null());
+        }
+
+        if let Ok(mut process) = command.spawn() {
+            if !config.quiet {
+                say!("🚀 Opening in {}...", editor);
+            }
+
+            if background {
+                relaunch_navigator(roots, config)?;
+                // Reap the editor if it's closed by now; one still open simply outlives slop
+                let _ = process.try_wait();
+                return Ok(());
+            }
+
+            // Wait for the editor to close
+            let _ = process.wait();
+            
+            // Capture quick notes
+            capture_quick_notes(path, config)?;
+            
+            // Return to slop navigator
+            relaunch_navigator(roots, config)?;
+            return Ok(());
+        }
+    }
+    
+    say_err!("⚠️  Could not find {} in PATH", config.default_editor);
+    say!("📁 Project at: {}", path.display());
+    Ok(())
+}
+
+/// `slop run --from-stdin`: each line goes through the same alias/URL detection
+/// as the navigator's search box, then is cloned or created with `default_template`.
+fn run_batch(roots: &[PathBuf], config: &VibeConfig, input: impl BufRead) -> Result<()> {
+    let mut selector = VibeSelector::new(String::new(), roots.to_vec())?;
+    let (mut created, mut cloned, mut skipped, mut failed) = (0, 0, 0, 0);
+
+    for line in input.lines() {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        selector.input_buffer = line.chars().filter(|&ch| is_query_char(ch)).collect();
+        if selector.config.aliases.contains_key(&selector.input_buffer) || selector.is_github_url(&selector.input_buffer) {
+            selector.handle_clone_repo(false, false)?;
+        } else {
+            selector.handle_template_selection(config.default_template.clone())?;
+        }
+        // Existing names come back as a collision; they're skipped below
+        let Some(result) = selector.selected.take().or_else(|| selector.name_collision.take()) else {
+            continue;
+        };
+
+        let name = result.path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+        if result.path.exists() {
+            say!("⏭️  {} already exists", name);
+            skipped += 1;
+            continue;
+        }
+
+        let outcome = match (&result.action, &result.git_url, &result.template) {
+            (SelectionAction::CloneRepo, Some(url), _) => {
+                if !config.quiet {
+                    say!("🌐 Cloning {}...", url);
+                }
+                let sparse_path = result.sparse_path.as_deref().or(result.subdir.as_deref().filter(|_| config.sparse_subdir));
+                clone_repository(
+                    url,
+                    &result.path,
+                    sparse_path,
+                    config.quiet,
+                    config.recurse_submodules,
+                    config.clone_protocol,
+                    &config.mirrors,
+                )
+                    .map(|_| checkout_ref(&result.path, result.git_ref.as_deref(), config.quiet))
+                    .and_then(|_| record_last_action(&LastAction::Cloned(result.path.clone())))
+                    .map(|_| cloned += 1)
+            }
+            (_, _, Some(template)) => validate_project_name(&name)
+                .and_then(|_| create_project_from_template(&result.path, template, config))
+                .and_then(|_| record_last_action(&LastAction::Created(result.path.clone())))
+                .map(|_| created += 1),
+            _ => continue,
+        };
+
+        match outcome.and_then(|_| update_access_time(&result.path)) {
+            Ok(()) => say!("✅ {}", name),
+            Err(e) => {
+                say_err!("❌ {}: {}", line, e);
+                failed += 1;
+            }
+        }
+    }
+
+    say!("{} created, {} cloned, {} skipped, {} failed", created, cloned, skipped, failed);
+    if failed > 0 {
+        return Err(anyhow::anyhow!("{} line(s) failed", failed));
+    }
+    Ok(())
+}
+
+/// The query as a directory, when it's written like a path (`./x`, `../x`,
+/// `~/x`, `/x`, `a/b`) and that directory exists. URLs never count.
+fn existing_dir_argument(query: &str) -> Option<PathBuf> {
+    let query = query.trim();
+    let path_like = query.starts_with('.') || query.starts_with('~') || query.contains('/') || query.contains('\\');
+    if query.is_empty() || !path_like || query.contains("://") {
+        return None;
+    }
+    let path = match query.strip_prefix("~/") {
+        Some(rest) => home_dir()?.join(rest),
+        None if query == "~" => home_dir()?,
+        None => PathBuf::from(query),
+    };
+    path.is_dir().then(|| fs::canonicalize(&path).ok()).flatten()
+}
+
+fn relaunch_navigator(roots: &[PathBuf], config: &VibeConfig) -> Result<()> {
+    // Scripts that opened something without a terminal are done at this point
+    if !io::stdin().is_terminal() || !io::stderr().is_terminal() {
+        return Ok(());
+    }
+    let current_exe = env::current_exe()?;
+    // Carry an editor picked with Ctrl+E or --editor over to the next navigator
+    let saved_editor = load_config(&get_config_file_path()?).unwrap_or_default().default_editor;
+    let editor = (config.default_editor != saved_editor).then_some(["--editor", config.default_editor.as_str()]);
+    let mut new_process = Command::new(current_exe)
+        .arg("run")
+        .args(config.quiet.then_some("--quiet"))
+        .args(config.accessible.then_some("--a11y"))
+        .args(editor.iter().flatten())
+        .env("slop_PATH", env::join_paths(roots)?)
+        .spawn()?;
+
+    let _ = new_process.wait();
+    Ok(())
+}
+
+/// Editors that open a `.code-workspace` file as one multi-root window.
+const WORKSPACE_EDITORS: &[&str] = &["code", "code-insiders", "cursor", "codium", "windsurf"];
+
+/// Open several projects at once: as one multi-root workspace when the editor
+/// supports it, otherwise each on its own.
+fn open_workspace(paths: &[PathBuf], roots: &[PathBuf], config: &VibeConfig) -> Result<()> {
+    for path in paths {
+        prepare_env_file(path, config);
+    }
+
+    let editor = editor_candidates(config).into_iter().find(|e| find_in_path(e).is_some());
+    match editor {
+        Some(editor) if WORKSPACE_EDITORS.contains(&editor) => {
+            let names: Vec<String> = paths
+                .iter()
+                .map(|p| p.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default())
+                .collect();
+            let workspace = serde_json::json!({
+                "folders": paths.iter().map(|p| serde_json::json!({ "path": p.to_string_lossy() })).collect::<Vec<_>>(),
+            });
+            let file = env::temp_dir().join(format!("slop-{}.code-workspace", names.join("+")));
+            fs::write(&file, serde_json::to_string_pretty(&workspace)?)
+                .with_context(|| format!("Failed to write {}", file.display()))?;
+
+            if !config.quiet {
+                say!("🚀 Opening {} in {}...", names.join(", "), editor);
+            }
+            let _ = editor_command(editor).arg(&file).status();
+        }
+        _ => {
+            for path in paths {
+                match launch_without_waiting(path, config) {
+                    Ok(editor) if !config.quiet => say!("🚀 {} in {}", path.display(), editor),
+                    Ok(_) => {}
+                    Err(e) => say_err!("⚠️  {}: {}", path.display(), e),
+                }
+            }
+        }
+    }
+
+    relaunch_navigator(roots, config)
+}
+
+/// Start one of `companion_editors` without waiting for it. Terminal editors
+/// can't share the terminal with default_editor, so they need a tmux window.
+fn start_companion_editor(path: &Path, editor: &str, config: &VibeConfig) {
+    let program = editor_program(editor);
+    let started = if TERMINAL_EDITORS.contains(&program.as_str()) {
+        if env::var("TMUX").is_err() {
+            say_err!("⚠️  Skipping {}: a second terminal editor needs tmux", editor);
+            return;
+        }
+        let name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+        Command::new("tmux")
+            .args(["new-window", "-d", "-c"])
+            .arg(path)
+            .arg("-n")
+            .arg(&name)
+            .arg(editor)
+            .status()
+            .is_ok_and(|s| s.success())
+    } else {
+        let mut command = editor_command(editor);
+        if program != "claude" {
+            command.arg(".");
+        }
+        command
+            .current_dir(path)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .is_ok()
+    };
+
+    if !started {
+        say_err!("⚠️  Could not start {}", editor);
+    } else if !config.quiet {
+        say!("🚀 Also opening in {}...", editor);
+    }
+}
+
+/// How to start the project for development: `run = "..."` in `.slop`, else a
+/// guess from its manifest.
+fn detect_dev_command(path: &Path) -> Option<String> {
+    if let Some(command) = slop_file_value(path, "run") {
+        return Some(command).filter(|c| !c.is_empty());
+    }
+
+    // A broken package.json, or one without a dev script, leaves the other checks a chance
+    let manifest = fs::read_to_string(path.join("package.json"))
+        .ok()
+        .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok());
+    if let Some(manifest) = manifest {
+        let runner = if path.join("pnpm-lock.yaml").exists() {
+            "pnpm"
+        } else if path.join("yarn.lock").exists() {
+            "yarn"
+        } else if path.join("bun.lockb").exists() || path.join("bun.lock").exists() {
+            "bun"
+        } else {
+            "npm"
+        };
+        if let Some(script) = ["dev", "start"].into_iter().find(|script| manifest["scripts"][script].is_string()) {
+            return Some(format!("{} run {}", runner, script));
+        }
+    }
+    if path.join("Cargo.toml").exists() {
+        return Some("cargo run".to_string());
+    }
+    if let Ok(makefile) = fs::read_to_string(path.join("Makefile")) {
+        if makefile.lines().any(|line| line.starts_with("dev:")) {
+            return Some("make dev".to_string());
+        }
+    }
+    if path.join("go.mod").exists() {
+        return Some("go run .".to_string());
+    }
+    if path.join("manage.py").exists() {
+        return Some("python3 manage.py runserver".to_string());
+    }
+    if path.join("pubspec.yaml").exists() {
+        return Some("dart run".to_string());
+    }
+    None
+}
+
+/// `run_on_open`: the dev command goes in a tmux pane beside the editor when
+/// we're inside tmux, otherwise it runs detached with its output in a log file.
+fn start_dev_command(path: &Path, config: &VibeConfig) {
+    let Some(command) = detect_dev_command(path) else {
+        return;
+    };
+
+    if env::var("TMUX").is_ok() {
+        let status = Command::new("tmux").args(["split-window", "-d", "-h", "-c"]).arg(path).arg(&command).status();
+        if status.is_ok_and(|s| s.success()) {
+            if !config.quiet {
+                say!("▶️  Running {} in a tmux pane", command);
+            }
+            return;
+        }
+    }
+
+    let name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    let started = get_data_dir()
+        .map(|dir| dir.join("logs"))
+        .and_then(|dir| {
+            fs::create_dir_all(&dir)?;
+            let log_path = dir.join(format!("{}.log", name));
+            let log = fs::File::create(&log_path)?;
+            Command::new("sh")
+                .arg("-c")
+                .arg(&command)
+                .current_dir(path)
+                .stdin(Stdio::null())
+                .stdout(log.try_clone()?)
+                .stderr(log)
+                .spawn()?;
+            Ok(log_path)
+        });
+    match started {
+        Ok(log_path) if !config.quiet => say!("▶️  Running {} (output in {})", command, log_path.display()),
+        Ok(_) => {}
+        Err(e) => say_err!("⚠️  Could not start {}: {}", command, e),
+    }
+}
+
+const TERMINAL_EDITORS: &[&str] = &["claude", "vim", "nvim", "vi", "nano", "hx", "helix", "emacs", "micro", "kak"];
+
+/// Launch an editor without waiting on it, so several projects can open at once.
+/// Terminal editors get their own tmux window when tmux is available; otherwise
+/// they run in the foreground one after another.
+fn launch_without_waiting(path: &Path, config: &VibeConfig) -> Result<String> {
+    let config = &with_project_editor(path, config);
+    for editor in editor_candidates(config) {
+        let program = editor_program(editor);
+        let is_terminal_editor = TERMINAL_EDITORS.contains(&program.as_str());
+
+        if is_terminal_editor && env::var("TMUX").is_ok() {
+            let window_name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+            let status = Command::new("tmux")
+                .arg("new-window")
+                .arg("-c")
+                .arg(path)
+                .arg("-n")
+                .arg(&window_name)
+                .arg(editor)
+                .status();
+            if status.is_ok_and(|s| s.success()) {
+                return Ok(format!("{} (tmux window {})", editor, window_name));
+            }
+            continue;
+        }
+
+        let mut command = editor_command(editor);
+        command.current_dir(path);
+        if program != "claude" {
+            command.arg(".");
+        }
+
+        if let Ok(mut process) = command.spawn() {
+            if is_terminal_editor {
+                let _ = process.wait();
+            }
+            return Ok(editor.to_string());
+        }
+    }
+
+    Err(anyhow::anyhow!("Could not find {} in PATH", config.default_editor))
+}
+
+/// Projects ordered by most recent open, using history first and mtimes after.
+fn most_recent_projects(projects: &[Project], count: usize) -> Vec<Project> {
+    let mut ordered: Vec<Project> = Vec::new();
+    for (_, path) in load_access_history().iter().rev() {
+        if ordered.len() >= count {
+            break;
+        }
+        if ordered.iter().any(|p| &p.path == path) {
+            continue;
+        }
+        if let Some(project) = projects.iter().find(|p| &p.path == path) {
+            ordered.push(project.clone());
+        }
+    }
+
+    let mut by_mtime: Vec<&Project> = projects.iter().collect();
+    by_mtime.sort_by_key(|p| std::cmp::Reverse(p.last_accessed));
+    for project in by_mtime {
+        if ordered.len() >= count {
+            break;
+        }
+        if !ordered.iter().any(|p| p.path == project.path) {
+            ordered.push(project.clone());
+        }
+    }
+    ordered
+}
+
+fn capture_quick_notes(project_path: &Path, config: &VibeConfig) -> Result<()> {
+    say!();
+    say!("💭 Quick thoughts about this session? (Enter to skip)");
+    print!("> ");
+    io::stdout().flush()?;
+    
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    let notes = input.trim();
+    
+    if !notes.is_empty() {
+        save_notes_to_project(project_path, notes, config.local_time)?;
+        say!("✅ Notes saved to project");
+    }
+    
+    Ok(())
+}
+
+fn save_notes_to_project(project_path: &Path, notes: &str, local_time: bool) -> Result<()> {
+    let notes_file = project_path.join("NOTES.md");
+    let timestamp = format_timestamp(&Utc::now(), local_time, "%Y-%m-%d %H:%M:%S %Z");
+    
+    let note_entry = format!("\n## {}\n{}\n", timestamp, notes);
+    
+    // Append to existing notes or create new file
+    if notes_file.exists() {
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&notes_file)?;
+        write!(file, "{}", note_entry)?;
+    } else {
+        let header = format!("# Notes\n{}", note_entry);
+        fs::write(&notes_file, header)?;
+    }
+    
+    Ok(())
+}
+
+fn decorate(text: &str) -> String {
+    if PLAIN_OUTPUT.load(Ordering::Relaxed) {
+        strip_emoji(text)
+    } else {
+        text.to_string()
+    }
+}
+
+fn strip_emoji(text: &str) -> String {
+    let is_emoji = |c: char| {
+        matches!(c as u32, 0x1F000..=0x1FAFF | 0x2600..=0x27BF | 0x2B00..=0x2BFF | 0xFE0F | 0x200D)
+    };
+    let stripped: String = text
+        .chars()
+        .filter(|c| !is_emoji(*c))
+        .map(|c| if c == '•' { '-' } else { c })
+        .collect();
+
+    // Lines that led with an icon shouldn't keep its padding
+    if text.starts_with(is_emoji) {
+        stripped.trim_start().to_string()
+    } else {
+        stripped
+    }
+}
+
+const ABSOLUTE_TIME_FORMAT: &str = "%Y-%m-%d %H:%M";
+
+/// How times show up in the navigator and reports.
+#[derive(Debug, Clone, PartialEq)]
+enum TimeFormat {
+    Relative,
+    /// "3 minutes ago"
+    Verbose,
+    /// A strftime pattern
+    Absolute(String),
+    Iso,
+}
+
+impl TimeFormat {
+    fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "relative" => Some(TimeFormat::Relative),
+            "verbose" => Some(TimeFormat::Verbose),
+            "absolute" => Some(TimeFormat::Absolute(ABSOLUTE_TIME_FORMAT.to_string())),
+            "iso" => Some(TimeFormat::Iso),
+            _ if name.contains('%') && is_valid_strftime(name) => Some(TimeFormat::Absolute(name.to_string())),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &str {
+        match self {
+            TimeFormat::Relative => "relative",
+            TimeFormat::Verbose => "verbose",
+            TimeFormat::Absolute(pattern) if pattern == ABSOLUTE_TIME_FORMAT => "absolute",
+            TimeFormat::Absolute(pattern) => pattern,
+            TimeFormat::Iso => "iso",
+        }
+    }
+}
+
+fn format_time(time: &DateTime<Utc>, config: &VibeConfig) -> String {
+    match &config.time_format {
+        TimeFormat::Relative => format_relative_time(time),
+        TimeFormat::Verbose => match time_ago(time) {
+            Some((1, unit)) => format!("1 {} ago", unit),
+            Some((count, unit)) => format!("{} {}s ago", count, unit),
+            None => "just now".to_string(),
+        },
+        TimeFormat::Absolute(pattern) => format_timestamp(time, config.local_time, pattern),
+        TimeFormat::Iso if config.local_time => time.with_timezone(&Local).to_rfc3339_opts(SecondsFormat::Secs, true),
+        TimeFormat::Iso => time.to_rfc3339_opts(SecondsFormat::Secs, true),
+    }
+}
+
+fn format_relative_time(time: &DateTime<Utc>) -> String {
+    match time_ago(time) {
+        Some((count, "minute")) => format!("{}m", count),
+        Some((count, "hour")) => format!("{}h", count),
+        Some((count, "day")) => format!("{}d", count),
+        Some((count, "month")) => format!("{}mo", count),
+        Some((count, _)) => format!("{}y", count),
+        None => "just now".to_string(),
+    }
+}
+
+/// How long ago `time` was in its largest whole unit ("minute", "hour", "day",
+/// "month" or "year"), counting calendar months rather than 30-day blocks.
+/// `None` for anything under a minute.
+fn time_ago(time: &DateTime<Utc>) -> Option<(i64, &'static str)> {
+    let now = Utc::now();
+    let seconds = now.signed_duration_since(*time).num_seconds();
+    let (minutes, hours, days) = (seconds / 60, seconds / 3600, seconds / 86400);
+
+    let mut months = i64::from(now.year() - time.year()) * 12 + i64::from(now.month()) - i64::from(time.month());
+    // Not a full month until the same day and time comes round again
+    if (now.day(), now.time()) < (time.day(), time.time()) {
+        months -= 1;
+    }
+
+    if minutes < 1 {
+        None
+    } else if minutes < 60 {
+        Some((minutes, "minute"))
+    } else if hours < 24 {
+        Some((hours, "hour"))
+    } else if months < 1 {
+        Some((days, "day"))
+    } else if months < 12 {
+        Some((months, "month"))
+    } else {
+        Some((months / 12, "year"))
+    }
+}
+
+fn format_timestamp(time: &DateTime<Utc>, local_time: bool, format: &str) -> String {
+    if local_time {
+        time.with_timezone(&Local).format(format).to_string()
+    } else {
+        time.format(format).to_string()
+    }
+}
+
+/// The origin URL from `.git/config`, normalised so SSH and HTTPS clones of the
+/// same repo compare equal. Read directly to avoid a `git` process per project.
+fn read_origin_url(path: &Path) -> Option<String> {
+    let config = fs::read_to_string(path.join(".git").join("config")).ok()?;
+    let mut in_origin = false;
+    for line in config.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_origin = line == "[remote \"origin\"]";
+        } else if let Some((key, value)) = line.split_once('=').filter(|_| in_origin) {
+            if key.trim() == "url" {
+                let url = value.trim();
+                let url = remote_web_url(url).unwrap_or_else(|| url.to_string());
+                return Some(url.trim_end_matches('/').trim_end_matches(".git").to_lowercase());
+            }
+        }
+    }
+    None
+}
+
+fn project_web_url(project: &Project) -> Result<String> {
+    if let ProjectType::Local = project.project_type {
+        return Err(anyhow::anyhow!("{} is not a git repo", project.name));
+    }
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(&project.path)
+        .args(["remote", "get-url", "origin"])
+        .output()?;
+    if !output.status.success() {
+        return Err(anyhow::anyhow!("{} has no origin remote", project.name));
+    }
+    let remote = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    remote_web_url(&remote).ok_or_else(|| anyhow::anyhow!("Don't know how to browse {}", remote))
+}
+
+/// Where `git worktree add` puts `branch` of the repo at `repo`: a sibling
+/// directory named `<repo>-<branch>`, so it shows up as its own project.
+fn worktree_path(repo: &Path, branch: &str) -> PathBuf {
+    let repo_name = repo.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    let branch = branch.trim().replace(['/', ' '], "-");
+    repo.with_file_name(format!("{}-{}", repo_name, branch))
+}
+
+/// Check out `branch` into a new worktree next to `repo`. A branch that exists
+/// neither locally nor on a remote is created from HEAD.
+fn add_worktree(repo: &Path, branch: &str) -> Result<PathBuf> {
+    if branch.is_empty() {
+        return Err(anyhow::anyhow!("Branch name cannot be empty"));
+    }
+    let path = worktree_path(repo, branch);
+    if path.exists() {
+        return Err(anyhow::anyhow!("{} already exists", path.display()));
+    }
 
+    let refs = Command::new("git")
+        .arg("-C")
+        .arg(repo)
+        .args(["for-each-ref", "--format=%(refname)"])
+        .arg(format!("refs/heads/{}", branch))
+        .arg(format!("refs/remotes/*/{}", branch))
+        .output()?;
+    let known = refs.status.success() && !refs.stdout.is_empty();
+
+    let mut command = Command::new("git");
+    command.arg("-C").arg(repo).args(["worktree", "add"]);
+    if known {
+        // A remote-only branch gets a local tracking branch via git's DWIM
+        command.arg(&path).arg(branch);
+    } else {
+        command.arg("-b").arg(branch).arg(&path);
+    }
+    let output = command.output()?;
     if !output.status.success() {
-        let error = String::from_utf8_lossy(&output.stderr);
-        return Err(anyhow::anyhow!("Git clone failed: {}", error));
+        return Err(anyhow::anyhow!(
+            "git worktree add failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    record_last_action(&LastAction::Worktree { repo: repo.to_path_buf(), path: path.clone() })?;
+    Ok(path)
+}
+
+/// `git@host:org/repo.git`, `ssh://git@host/org/repo` or `https://host/org/repo.git`
+/// -> `https://host/org/repo`. Local and file remotes have no web page.
+fn remote_web_url(remote: &str) -> Option<String> {
+    let (host, repo_path) = if let Ok(url) = Url::parse(remote) {
+        if !matches!(url.scheme(), "https" | "http" | "ssh" | "git") {
+            return None;
+        }
+        (url.host_str()?.to_string(), url.path().trim_start_matches('/').to_string())
+    } else {
+        // scp-like syntax: [user@]host:path
+        let (user_host, repo_path) = remote.split_once(':')?;
+        let host = user_host.rsplit('@').next()?;
+        if host.is_empty() || host.contains('/') {
+            return None;
+        }
+        (host.to_string(), repo_path.trim_start_matches('/').to_string())
+    };
+
+    let repo_path = repo_path.trim_end_matches('/').trim_end_matches(".git");
+    if repo_path.is_empty() {
+        return None;
+    }
+    Some(format!("https://{}/{}", host, repo_path))
+}
+
+fn open_in_browser(url: &str) -> Result<()> {
+    let mut command = if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else if cfg!(target_os = "windows") {
+        let mut command = Command::new("cmd");
+        command.args(["/C", "start", ""]);
+        command
+    } else {
+        Command::new("xdg-open")
+    };
+    command
+        .arg(url)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|_| anyhow::anyhow!("No browser opener found for {}", url))?;
+    Ok(())
+}
+
+fn get_clipboard_content() -> Result<String> {
+    let mut ctx: ClipboardContext = ClipboardProvider::new()
+        .map_err(|e| anyhow::anyhow!("Failed to initialize clipboard: {}", e))?;
+    let content = ctx.get_contents()
+        .map_err(|e| anyhow::anyhow!("Failed to get clipboard content: {}", e))?;
+    Ok(content.trim().to_string())
+}
+
+fn set_clipboard_content(content: &str) -> Result<()> {
+    let clipboard_result = ClipboardProvider::new()
+        .and_then(|mut ctx: ClipboardContext| ctx.set_contents(content.to_string()));
+    if clipboard_result.is_ok() {
+        return Ok(());
+    }
+
+    // Fall back to platform clipboard commands
+    let commands: [(&str, &[&str]); 4] = [
+        ("pbcopy", &[]),
+        ("wl-copy", &[]),
+        ("xclip", &["-selection", "clipboard"]),
+        ("xsel", &["--clipboard", "--input"]),
+    ];
+    for (program, args) in commands {
+        let Ok(mut child) = Command::new(program).args(args).stdin(Stdio::piped()).spawn() else {
+            continue;
+        };
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(content.as_bytes())?;
+        }
+        if child.wait().is_ok_and(|status| status.success()) {
+            return Ok(());
+        }
+    }
+
+    Err(anyhow::anyhow!("No clipboard available"))
+}
+
+fn update_access_time(path: &Path) -> Result<()> {
+    // Touch a hidden file to update access time
+    let access_file = path.join(".slop_access");
+    fs::write(&access_file, "")?;
+
+    // History is best-effort; never block opening a project on it
+    record_access(path).ok();
+    Ok(())
+}
+
+fn get_last_template_path() -> Result<PathBuf> {
+    Ok(get_data_dir()?.join("last_template"))
+}
+
+fn load_last_template() -> Option<ProjectTemplate> {
+    let content = fs::read_to_string(get_last_template_path().ok()?).ok()?;
+    ProjectTemplate::from_name(content.trim())
+}
+
+fn save_last_template(template: &ProjectTemplate) -> Result<()> {
+    let path = get_last_template_path()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, format!("{}\n", template.display_name()))?;
+    Ok(())
+}
+
+fn get_last_destination_path() -> Result<PathBuf> {
+    Ok(get_data_dir()?.join("last_destination.tsv"))
+}
+
+/// Per-project editors live with slop's data, never in the project: a `.slop`
+/// file is committed with the repo, so a clone could pick the program slop runs.
+fn get_project_editors_path() -> Result<PathBuf> {
+    Ok(get_data_dir()?.join("project_editors.tsv"))
+}
+
+fn load_project_editor(project: &Path) -> Option<String> {
+    let content = fs::read_to_string(get_project_editors_path().ok()?).ok()?;
+    content.lines().find_map(|line| {
+        let (path, editor) = line.split_once('\t')?;
+        (Path::new(path) == project && !editor.is_empty()).then(|| editor.to_string())
+    })
+}
+
+/// Set (or with `None`, forget) the editor `project` always opens in.
+fn save_project_editor(project: &Path, editor: Option<&str>) -> Result<()> {
+    let path = get_project_editors_path()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut content: String = fs::read_to_string(&path)
+        .unwrap_or_default()
+        .lines()
+        .filter(|line| line.split_once('\t').is_none_or(|(other, _)| Path::new(other) != project))
+        .map(|line| format!("{}\n", line))
+        .collect();
+    if let Some(editor) = editor {
+        content.push_str(&format!("{}\t{}\n", project.display(), editor));
+    }
+    fs::write(path, content)?;
+    Ok(())
+}
+
+fn destination_key(action: &SelectionAction) -> &'static str {
+    match action {
+        SelectionAction::CloneRepo => "clone",
+        _ => "create",
+    }
+}
+
+/// The root a create (or clone) last went to, one line per kind.
+fn load_last_destination(action: &SelectionAction) -> Option<PathBuf> {
+    let content = fs::read_to_string(get_last_destination_path().ok()?).ok()?;
+    content.lines().find_map(|line| {
+        let (kind, root) = line.split_once('\t')?;
+        (kind == destination_key(action)).then(|| PathBuf::from(root))
+    })
+}
+
+fn save_last_destination(action: &SelectionAction, root: &Path) -> Result<()> {
+    let path = get_last_destination_path()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let key = destination_key(action);
+    let mut content: String = fs::read_to_string(&path)
+        .unwrap_or_default()
+        .lines()
+        .filter(|line| line.split_once('\t').is_none_or(|(kind, _)| kind != key))
+        .map(|line| format!("{}\n", line))
+        .collect();
+    content.push_str(&format!("{}\t{}\n", key, root.display()));
+    fs::write(path, content)?;
+    Ok(())
+}
+
+/// The one action `slop undo` can reverse.
+enum LastAction {
+    Created(PathBuf),
+    Cloned(PathBuf),
+    Deleted { original: PathBuf, trashed: PathBuf },
+    /// Undone with `git worktree remove`, so the repo forgets it too
+    Worktree { repo: PathBuf, path: PathBuf },
+}
+
+fn get_last_action_path() -> Result<PathBuf> {
+    Ok(get_data_dir()?.join("last_action.tsv"))
+}
+
+fn record_last_action(action: &LastAction) -> Result<()> {
+    let path = get_last_action_path()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let line = match action {
+        LastAction::Created(project) => format!("created\t{}", project.display()),
+        LastAction::Cloned(project) => format!("cloned\t{}", project.display()),
+        LastAction::Deleted { original, trashed } => {
+            format!("deleted\t{}\t{}", original.display(), trashed.display())
+        }
+        LastAction::Worktree { repo, path } => format!("worktree\t{}\t{}", path.display(), repo.display()),
+    };
+    fs::write(path, line + "\n")?;
+    Ok(())
+}
+
+fn load_last_action() -> Option<LastAction> {
+    let content = fs::read_to_string(get_last_action_path().ok()?).ok()?;
+    let mut fields = content.trim_end_matches('\n').split('\t');
+    match (fields.next()?, fields.next(), fields.next()) {
+        ("created", Some(path), None) => Some(LastAction::Created(PathBuf::from(path))),
+        ("cloned", Some(path), None) => Some(LastAction::Cloned(PathBuf::from(path))),
+        ("deleted", Some(original), Some(trashed)) => Some(LastAction::Deleted {
+            original: PathBuf::from(original),
+            trashed: PathBuf::from(trashed),
+        }),
+        ("worktree", Some(path), Some(repo)) => Some(LastAction::Worktree {
+            repo: PathBuf::from(repo),
+            path: PathBuf::from(path),
+        }),
+        _ => None,
+    }
+}
+
+fn clear_last_action() {
+    if let Ok(path) = get_last_action_path() {
+        let _ = fs::remove_file(path);
+    }
+}
+
+/// Move `path` into slop's trash. Only the latest item is kept, since undo only goes one step back.
+fn move_to_trash(path: &Path) -> Result<PathBuf> {
+    let name = path.file_name().ok_or_else(|| anyhow::anyhow!("Nothing to trash at {}", path.display()))?;
+    let data_dir = get_data_dir()?;
+
+    // Move in beside the trash first, so a failed move leaves the previous item alone
+    let incoming = data_dir.join("trash.incoming");
+    if incoming.exists() {
+        fs::remove_dir_all(&incoming)?;
+    }
+    fs::create_dir_all(&incoming)?;
+    if let Err(e) = fs::rename(path, incoming.join(name)) {
+        let _ = fs::remove_dir(&incoming);
+        return Err(e.into());
+    }
+
+    let trash_dir = data_dir.join("trash");
+    if trash_dir.exists() {
+        fs::remove_dir_all(&trash_dir)
+            .with_context(|| format!("{} is in {}, but the old trash couldn't be emptied", path.display(), incoming.display()))?;
+    }
+    fs::rename(&incoming, &trash_dir)?;
+    Ok(trash_dir.join(name))
+}
+
+/// Whether `error` is a rename that failed because it crossed filesystems.
+fn crosses_devices(error: &anyhow::Error) -> bool {
+    error.downcast_ref::<io::Error>().is_some_and(|e| e.kind() == io::ErrorKind::CrossesDevices)
+}
+
+fn get_history_file_path() -> Result<PathBuf> {
+    Ok(get_data_dir()?.join("history.tsv"))
+}
+
+fn record_access(path: &Path) -> Result<()> {
+    let history_path = get_history_file_path()?;
+    if let Some(parent) = history_path.parent() {
+        fs::create_dir_all(parent)?;
     }
 
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&history_path)?;
+    writeln!(file, "{}\t{}", Utc::now().to_rfc3339(), path.display())?;
     Ok(())
 }
 
-fn open_in_editor(path: &PathBuf, config: &VibeConfig) -> Result<()> {
-    // Change to project directory first
-    env::set_current_dir(path)?;
-    
-    // Try configured editor first, then fallbacks
-    let mut editors_to_try = vec![config.default_editor.as_str()];
-    
-    // Add fallbacks if they're not already the default
-    if config.default_editor != "claude" {
-        editors_to_try.push("claude");
+/// Append a JSON line to `activity_log`, if set. Purely a local file; slop
+/// never sends it (or anything else) over the network.
+fn log_activity(config: &VibeConfig, action: &str, path: &Path, editor: Option<&str>) {
+    let Some(log_path) = &config.activity_log else {
+        return;
+    };
+    let line = serde_json::json!({
+        "timestamp": Utc::now().to_rfc3339(),
+        "action": action,
+        "path": path.to_string_lossy(),
+        "editor": editor,
+    });
+    let written = log_path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::OpenOptions::new().create(true).append(true).open(log_path))
+        .and_then(|mut file| writeln!(file, "{}", line));
+    if let Err(e) = written {
+        say_err!("⚠️  Could not write activity log {}: {}", log_path.display(), e);
+    }
+}
+
+/// `slop stats --from-log`: what the activity log says you've been doing.
+fn print_log_stats(config: &VibeConfig, json: bool, cutoff: Option<DateTime<Utc>>) -> Result<()> {
+    let log_path = config
+        .activity_log
+        .as_ref()
+        .ok_or_else(|| anyhow::anyhow!("No activity log yet; set activity_log = \"<file>\" in the config to start one"))?;
+    let content = match fs::read_to_string(log_path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e).with_context(|| format!("Failed to read {}", log_path.display())),
+    };
+
+    let entries: Vec<(DateTime<Utc>, String, PathBuf, Option<String>)> = content
+        .lines()
+        .filter_map(|line| {
+            let entry: serde_json::Value = serde_json::from_str(line).ok()?;
+            let timestamp = DateTime::parse_from_rfc3339(entry["timestamp"].as_str()?).ok()?.with_timezone(&Utc);
+            Some((
+                timestamp,
+                entry["action"].as_str()?.to_string(),
+                PathBuf::from(entry["path"].as_str()?),
+                entry["editor"].as_str().map(str::to_string),
+            ))
+        })
+        .filter(|(timestamp, ..)| cutoff.is_none_or(|cutoff| *timestamp >= cutoff))
+        .collect();
+
+    let week_ago = Utc::now() - chrono::Duration::days(7);
+    let this_week = entries.iter().filter(|(timestamp, ..)| *timestamp >= week_ago).count();
+    let mut actions: BTreeMap<&str, usize> = BTreeMap::new();
+    let mut editors: BTreeMap<&str, usize> = BTreeMap::new();
+    let mut projects: HashMap<&Path, usize> = HashMap::new();
+    for (_, action, path, editor) in &entries {
+        *actions.entry(action).or_default() += 1;
+        if let Some(editor) = editor {
+            *editors.entry(editor).or_default() += 1;
+        }
+        *projects.entry(path).or_default() += 1;
+    }
+    let mut top_projects: Vec<(&Path, usize)> = projects.into_iter().collect();
+    top_projects.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+    top_projects.truncate(5);
+    let project_name = |path: &Path| path.file_name().map_or_else(|| path.display().to_string(), |n| n.to_string_lossy().to_string());
+    let first = entries.iter().map(|(timestamp, ..)| *timestamp).min();
+    let last = entries.iter().map(|(timestamp, ..)| *timestamp).max();
+
+    if json {
+        let stats = serde_json::json!({
+            "log": log_path.display().to_string(),
+            "entries": entries.len(),
+            "this_week": this_week,
+            "actions": actions,
+            "editors": editors,
+            "top_projects": top_projects
+                .iter()
+                .map(|(path, count)| serde_json::json!({ "path": path.display().to_string(), "count": count }))
+                .collect::<Vec<_>>(),
+            "first": first.map(|t| t.to_rfc3339()),
+            "last": last.map(|t| t.to_rfc3339()),
+        });
+        println!("{}", serde_json::to_string_pretty(&stats)?);
+        return Ok(());
+    }
+
+    let counts = |map: &BTreeMap<&str, usize>| {
+        map.iter().map(|(name, count)| format!("{} {}", name, count)).collect::<Vec<_>>().join(", ")
+    };
+    say!("📊 slop activity ({})", log_path.display());
+    say!();
+    if entries.is_empty() {
+        say!("Nothing logged yet");
+        return Ok(());
     }
-    if config.default_editor != "cursor" {
-        editors_to_try.push("cursor");
+    say!("Entries:           {} ({} this week)", entries.len(), this_week);
+    say!("Actions:           {}", counts(&actions));
+    if !editors.is_empty() {
+        say!("Editors:           {}", counts(&editors));
     }
-    if config.default_editor != "code" {
-        editors_to_try.push("code");
+    let top: Vec<String> = top_projects
+        .iter()
+        .map(|(path, count)| format!("{} ({})", project_name(path), count))
+        .collect();
+    say!("Most active:       {}", top.join(", "));
+    if let (Some(first), Some(last)) = (first, last) {
+        say!("Logged:            {} – {}", format_time(&first, config), format_time(&last, config));
     }
-    
-    for editor in &editors_to_try {
-        let child = if editor == &"claude" {
-            // Claude doesn't need a path argument - it opens in current directory
-            Command::new(editor).spawn()
-        } else {
-            // Other editors need the path
-            Command::new(editor).arg(".").spawn()
-        };
-            
-        if let Ok(mut process) = child {
-            println!("🚀 Opening in {}...", editor);
-            
-            // Wait for the editor to close
-            let _ = process.wait();
-            
-            // Capture quick notes
-            capture_quick_notes(path)?;
-            
-            // Return to slop navigator
-            let current_exe = env::current_exe()?;
-            let mut new_process = Command::new(current_exe)
-                .arg("run")
-                .arg("--path")
-                .arg(path.parent().unwrap_or(path))
-                .spawn()?;
-            
-            let _ = new_process.wait();
-            return Ok(());
+    Ok(())
+}
+
+/// Point history entries for `from` (and anything inside it) at `to`.
+fn move_access_history(from: &Path, to: &Path) -> Result<()> {
+    let history_path = get_history_file_path()?;
+    let content = match fs::read_to_string(&history_path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut rewritten = String::with_capacity(content.len());
+    for line in content.lines() {
+        match line.split_once('\t') {
+            Some((timestamp, path)) if Path::new(path).starts_with(from) => {
+                let rest = Path::new(path).strip_prefix(from).unwrap_or(Path::new(""));
+                let moved = if rest.as_os_str().is_empty() { to.to_path_buf() } else { to.join(rest) };
+                rewritten.push_str(&format!("{}\t{}\n", timestamp, moved.display()));
+            }
+            _ => {
+                rewritten.push_str(line);
+                rewritten.push('\n');
+            }
         }
     }
-    
-    eprintln!("⚠️  Could not find {} in PATH", config.default_editor);
-    println!("📁 Project at: {}", path.display());
+    fs::write(&history_path, rewritten)?;
     Ok(())
 }
 
-fn capture_quick_notes(project_path: &PathBuf) -> Result<()> {
-    println!();
-    println!("💭 Quick thoughts about this session? (Enter to skip)");
-    print!("> ");
-    io::stdout().flush()?;
-    
-    let mut input = String::new();
-    io::stdin().read_line(&mut input)?;
-    let notes = input.trim();
-    
-    if !notes.is_empty() {
-        save_notes_to_project(project_path, notes)?;
-        println!("✅ Notes saved to project");
+fn load_access_history() -> Vec<(DateTime<Utc>, PathBuf)> {
+    let Ok(content) = get_history_file_path().and_then(|p| Ok(fs::read_to_string(p)?)) else {
+        return Vec::new();
+    };
+
+    content
+        .lines()
+        .filter_map(|line| {
+            let (timestamp, path) = line.split_once('\t')?;
+            let timestamp = DateTime::parse_from_rfc3339(timestamp).ok()?;
+            Some((timestamp.with_timezone(&Utc), PathBuf::from(path)))
+        })
+        .collect()
+}
+
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "K", "M", "G", "T"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 || size >= 10.0 {
+        format!("{:.0}{}", size, UNITS[unit])
+    } else {
+        format!("{:.1}{}", size, UNITS[unit])
     }
-    
-    Ok(())
 }
 
-fn save_notes_to_project(project_path: &PathBuf, notes: &str) -> Result<()> {
-    let notes_file = project_path.join("NOTES.md");
-    let timestamp = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC");
-    
-    let note_entry = format!("\n## {}\n{}\n", timestamp, notes);
-    
-    // Append to existing notes or create new file
-    if notes_file.exists() {
-        let mut file = fs::OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&notes_file)?;
-        write!(file, "{}", note_entry)?;
+fn detect_language(path: &Path) -> &'static str {
+    if path.join("Cargo.toml").exists() {
+        "Rust"
+    } else if path.join("tsconfig.json").exists() {
+        "TypeScript"
+    } else if path.join("package.json").exists() {
+        "JavaScript"
+    } else if path.join("go.mod").exists() {
+        "Go"
+    } else if path.join("dune-project").exists() {
+        "OCaml"
+    } else if path.join("gleam.toml").exists() {
+        "Gleam"
+    } else if path.join("pubspec.yaml").exists() {
+        "Dart"
+    } else if path.join("pyproject.toml").exists()
+        || path.join("requirements.txt").exists()
+        || path.join("main.py").exists()
+    {
+        "Python"
     } else {
-        let header = format!("# Notes\n{}", note_entry);
-        fs::write(&notes_file, header)?;
+        "Other"
     }
-    
-    Ok(())
 }
 
-fn get_clipboard_content() -> Result<String> {
-    let mut ctx: ClipboardContext = ClipboardProvider::new()
-        .map_err(|e| anyhow::anyhow!("Failed to initialize clipboard: {}", e))?;
-    let content = ctx.get_contents()
-        .map_err(|e| anyhow::anyhow!("Failed to get clipboard content: {}", e))?;
-    Ok(content.trim().to_string())
+/// `--since` durations: a number with m, h, d or w (`90m`, `12h`, `7d`, `2w`); a bare number is days.
+fn parse_since(text: &str) -> Result<chrono::Duration> {
+    let text = text.trim();
+    let (number, unit) = text.split_at(text.find(|c: char| !c.is_ascii_digit()).unwrap_or(text.len()));
+    let amount: i64 = number
+        .parse()
+        .map_err(|_| anyhow::anyhow!("'{}' isn't a duration like 7d, 2w or 12h", text))?;
+    match unit.trim() {
+        "m" | "min" => Ok(chrono::Duration::minutes(amount)),
+        "h" => Ok(chrono::Duration::hours(amount)),
+        "" | "d" => Ok(chrono::Duration::days(amount)),
+        "w" => Ok(chrono::Duration::weeks(amount)),
+        other => Err(anyhow::anyhow!("Unknown unit '{}' in '{}' (use m, h, d or w)", other, text)),
+    }
 }
 
-fn update_access_time(path: &PathBuf) -> Result<()> {
-    // Touch a hidden file to update access time
-    let access_file = path.join(".slop_access");
-    fs::write(&access_file, "")?;
+fn print_stats(projects: &[Project], config: &VibeConfig, json: bool) -> Result<()> {
+    let history = load_access_history();
+    let week_ago = Utc::now() - chrono::Duration::days(7);
+
+    let mut open_counts: HashMap<&Path, usize> = HashMap::new();
+    let mut opened_this_week: Vec<&Project> = Vec::new();
+    for project in projects {
+        let opens: Vec<&DateTime<Utc>> = history
+            .iter()
+            .filter(|(_, path)| path == &project.path)
+            .map(|(timestamp, _)| timestamp)
+            .collect();
+        if !opens.is_empty() {
+            open_counts.insert(&project.path, opens.len());
+        }
+
+        // Without history, fall back to filesystem times
+        let recent = if history.is_empty() {
+            project.last_accessed >= week_ago
+        } else {
+            opens.iter().any(|timestamp| **timestamp >= week_ago)
+        };
+        if recent {
+            opened_this_week.push(project);
+        }
+    }
+
+    let mut most_opened: Vec<(&Project, usize)> = projects
+        .iter()
+        .filter_map(|p| open_counts.get(p.path.as_path()).map(|count| (p, *count)))
+        .collect();
+    most_opened.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.name.cmp(&b.0.name)));
+    most_opened.truncate(5);
+
+    let mut languages: BTreeMap<&str, usize> = BTreeMap::new();
+    for project in projects {
+        *languages.entry(detect_language(&project.path)).or_default() += 1;
+    }
+
+    let oldest_untouched = projects.iter().min_by_key(|p| p.last_accessed);
+
+    let git_repos = projects
+        .iter()
+        .filter(|p| matches!(p.project_type, ProjectType::GitRepo))
+        .count();
+    let local = projects.len() - git_repos;
+
+    let now = Utc::now();
+    let average_age_days = if projects.is_empty() {
+        0.0
+    } else {
+        let total: i64 = projects.iter().map(|p| (now - p.created).num_seconds()).sum();
+        total as f64 / projects.len() as f64 / 86400.0
+    };
+
+    if json {
+        let stats = serde_json::json!({
+            "total_projects": projects.len(),
+            "git_repos": git_repos,
+            "local": local,
+            "average_age_days": average_age_days,
+            "history_available": !history.is_empty(),
+            "opened_this_week": opened_this_week.iter().map(|p| &p.name).collect::<Vec<_>>(),
+            "most_opened": most_opened
+                .iter()
+                .map(|(p, count)| serde_json::json!({ "name": p.name, "opens": count }))
+                .collect::<Vec<_>>(),
+            "languages": languages,
+            "oldest_untouched": oldest_untouched.map(|p| serde_json::json!({
+                "name": p.name,
+                "last_accessed": p.last_accessed.to_rfc3339(),
+            })),
+        });
+        println!("{}", serde_json::to_string_pretty(&stats)?);
+        return Ok(());
+    }
+
+    say!("📊 slop stats");
+    say!();
+    say!("Projects:          {} ({} git, {} local)", projects.len(), git_repos, local);
+    say!("Average age:       {:.0}d", average_age_days);
+    say!("Opened this week:  {}", opened_this_week.len());
+    if most_opened.is_empty() {
+        say!("Most opened:       (no history yet)");
+    } else {
+        let top: Vec<String> = most_opened
+            .iter()
+            .map(|(p, count)| format!("{} ({})", p.name, count))
+            .collect();
+        say!("Most opened:       {}", top.join(", "));
+    }
+    if let Some(project) = oldest_untouched {
+        say!(
+            "Oldest untouched:  {} ({})",
+            project.name,
+            format_time(&project.last_accessed, config)
+        );
+    }
+    let breakdown: Vec<String> = languages
+        .iter()
+        .map(|(language, count)| format!("{} {}", language, count))
+        .collect();
+    say!("Languages:         {}", breakdown.join(", "));
     Ok(())
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
+    PLAIN_OUTPUT.store(cli.plain, Ordering::Relaxed);
 
     match cli.command {
         None => {
             print_global_help();
             std::process::exit(2);
         }
-        Some(Commands::Init { path, projects_path }) => {
+        Some(Commands::Init { path, projects_path, shell }) => {
             let script_path = env::current_exe()?;
-            let projects_path = path.or(projects_path).unwrap_or_else(get_default_projects_path);
-            let projects_path = projects_path.canonicalize().unwrap_or(projects_path);
-            
-            let path_arg = format!(" --path \"{}\"", projects_path.display());
+            let projects_path = path.or(projects_path).map(|p| p.canonicalize().unwrap_or(p));
+            // PowerShell sets PSModulePath; POSIX shells set SHELL, even on Windows
+            // (Git Bash, MSYS), and may inherit PSModulePath from a parent pwsh
+            let powershell = match shell.as_deref() {
+                Some(shell) => shell == "powershell",
+                None => env::var_os("PSModulePath").is_some() && env::var_os("SHELL").is_none(),
+            };
+            if powershell {
+                print_powershell_init(&script_path, projects_path.as_deref());
+                return Ok(());
+            }
+
+            // Without an explicit path, roots are resolved at run time so slop_PATH
+            // lists and projects_paths keep working
+            let path_arg = match projects_path {
+                Some(projects_path) => format!(" --path \"{}\"", projects_path.display()),
+                None => String::new(),
+            };
             
             println!(
                 r#"slop() {{
@@ -1593,7 +6910,7 @@ async fn main() -> Result<()> {
     "$script_path" run{} 2>/dev/tty;
   else
     case "$1" in
-      --help|-h|help|config|init)
+      {})
         # Pass these commands directly to slop
         "$script_path" "$@"
         ;;
@@ -1606,84 +6923,470 @@ async fn main() -> Result<()> {
 }}"#,
                 script_path.display(),
                 path_arg,
+                PASSTHROUGH_COMMANDS.join("|"),
                 path_arg
             );
         }
+        Some(Commands::Undo { yes }) => {
+            let Some(action) = load_last_action() else {
+                say!("Nothing to undo");
+                return Ok(());
+            };
+
+            let question = match &action {
+                LastAction::Created(path) => format!("Move the new project {} to the trash?", path.display()),
+                LastAction::Cloned(path) => format!("Move the clone {} to the trash?", path.display()),
+                LastAction::Deleted { original, .. } => format!("Restore {}?", original.display()),
+                LastAction::Worktree { path, .. } => format!("Remove the worktree {}?", path.display()),
+            };
+            if !yes {
+                print!("{} [y/N] ", question);
+                io::stdout().flush()?;
+
+                let mut input = String::new();
+                io::stdin().read_line(&mut input)?;
+                if !matches!(input.trim(), "y" | "Y" | "yes") {
+                    say!("Cancelled");
+                    return Ok(());
+                }
+            }
+
+            match action {
+                LastAction::Created(path) | LastAction::Cloned(path) => {
+                    if !path.exists() {
+                        return Err(anyhow::anyhow!("{} no longer exists", path.display()));
+                    }
+                    move_to_trash(&path).with_context(|| format!("Failed to move {} to the trash", path.display()))?;
+                    clear_last_action();
+                    say!("✅ Moved {} to the trash", path.display());
+                }
+                LastAction::Deleted { original, trashed } => {
+                    if original.exists() {
+                        return Err(anyhow::anyhow!("{} exists again; not overwriting it", original.display()));
+                    }
+                    if !trashed.exists() {
+                        return Err(anyhow::anyhow!("{} is no longer in the trash", original.display()));
+                    }
+                    fs::rename(&trashed, &original)
+                        .with_context(|| format!("Failed to restore {}", original.display()))?;
+                    clear_last_action();
+                    say!("✅ Restored {}", original.display());
+                }
+                LastAction::Worktree { repo, path } => {
+                    // Opening it left slop's own access marker behind; anything else
+                    // uncommitted still makes git refuse, since there's no --force
+                    let _ = fs::remove_file(path.join(".slop_access"));
+                    let output = Command::new("git")
+                        .arg("-C")
+                        .arg(&repo)
+                        .args(["worktree", "remove"])
+                        .arg(&path)
+                        .output()?;
+                    if !output.status.success() {
+                        return Err(anyhow::anyhow!(
+                            "git worktree remove failed: {}",
+                            String::from_utf8_lossy(&output.stderr).trim()
+                        ));
+                    }
+                    clear_last_action();
+                    say!("✅ Removed the worktree {} (its branch is kept)", path.display());
+                }
+            }
+        }
+        Some(Commands::Resume { path, count }) => {
+            let roots = path.map(|p| vec![p]).unwrap_or_else(get_default_projects_roots);
+            let mut config = load_config(&get_config_file_path()?).unwrap_or_default();
+            ensure_roots(&roots)?;
+            let projects = scan_projects(&roots, &config.scan_options())?;
+
+            let recent = most_recent_projects(&projects, count.unwrap_or(config.resume_count));
+            if recent.is_empty() {
+                say!("No projects to resume");
+            } else {
+                first_run_editor_setup(&mut config)?;
+            }
+            for project in &recent {
+                prepare_env_file(&project.path, &config);
+                match launch_without_waiting(&project.path, &config) {
+                    Ok(editor) => {
+                        update_access_time(&project.path)?;
+                        log_activity(&config, "open", &project.path, Some(&editor));
+                        if !config.quiet {
+                            say!("🚀 {} in {}", project.name, editor);
+                        }
+                    }
+                    Err(e) => say_err!("⚠️  {}: {}", project.name, e),
+                }
+            }
+        }
+        Some(Commands::Reindex) => {
+            let config = load_config(&get_config_file_path()?).unwrap_or_default();
+            let discovery = config.discovery().ok_or_else(|| anyhow::anyhow!("Could not find your home directory"))?;
+            let repos = reindex(&discovery, &config.ignore_dirs)?;
+            say!("🔎 Found {} git repos under {}", repos.len(), discovery.dir.display());
+            if !config.discover_repos {
+                say!("   Set discover_repos = true in the config to list them in the navigator");
+            }
+
+            if let Some(index_path) = config.index_path() {
+                let roots = get_default_projects_roots();
+                if roots.iter().all(|root| root.is_dir()) {
+                    let projects = scan_projects(&roots, &config.scan_options())?;
+                    save_index(&index_path, &roots, &projects)?;
+                    say!("🗂️  Indexed {} projects", projects.len());
+                }
+            }
+        }
+        Some(Commands::Stats { path, json, from_log, since }) => {
+            let roots = path.map(|p| vec![p]).unwrap_or_else(get_default_projects_roots);
+            let config = load_config(&get_config_file_path()?).unwrap_or_default();
+            let cutoff = since.map(|since| Utc::now() - since);
+            if from_log {
+                return print_log_stats(&config, json, cutoff);
+            }
+            ensure_roots(&roots)?;
+            let mut projects = scan_projects(&roots, &config.scan_options())?;
+            if let Some(cutoff) = cutoff {
+                projects.retain(|p| p.last_accessed >= cutoff || p.created >= cutoff);
+            }
+            print_stats(&projects, &config, json)?;
+        }
+        Some(Commands::New { path, name, from, no_substitute, open }) => {
+            validate_project_name(&name)?;
+            let roots = path.map(|p| vec![p]).unwrap_or_else(get_default_projects_roots);
+            let config = load_config(&get_config_file_path()?).unwrap_or_default();
+            let selector = VibeSelector::new(String::new(), roots.clone())?;
+            let from = config.aliases.get(&from).unwrap_or(&from);
+            // Any git URL works as a template; only GitHub shorthand needs expanding
+            let url = if from.contains("://") || from.starts_with("git@") {
+                from.clone()
+            } else {
+                selector.normalize_github_url(from)
+            };
+            let destination = roots[0].join(&name);
+            if destination.exists() {
+                return Err(anyhow::anyhow!("{} already exists", destination.display()));
+            }
+
+            if !config.quiet {
+                say!("🧩 Creating {} from {}...", name, url);
+            }
+            clone_repository(&url, &destination, None, config.quiet, false, config.clone_protocol, &config.mirrors)?;
+            if let Err(e) = detach_template(&destination, &name, !no_substitute) {
+                // Don't leave a half-made copy that still points at the template
+                let _ = fs::remove_dir_all(&destination);
+                return Err(e);
+            }
+            record_last_action(&LastAction::Created(destination.clone()))?;
+            update_access_time(&destination)?;
+            log_activity(&config, "create", &destination, open.then_some(config.default_editor.as_str()));
+            if open {
+                open_in_editor(&destination, &roots, &config)?;
+            } else if !config.quiet {
+                say!("📁 Created {}", destination.display());
+            }
+        }
+        Some(Commands::Remove { path, name, yes }) => {
+            let roots = path.map(|p| vec![p]).unwrap_or_else(get_default_projects_roots);
+            let mut selector = VibeSelector::new(String::new(), roots)?;
+            let project = selector.find_project_by_name(&name)?;
+
+            say!("🗑️  {}", project.name);
+            say!("   {}", project.path.display());
+            let warnings = unsaved_work(&project);
+            if !warnings.is_empty() {
+                say_err!("⚠️  This project has unsaved work:");
+                for warning in &warnings {
+                    say_err!("   {}", warning);
+                }
+            }
+
+            if !yes {
+                if warnings.is_empty() {
+                    print!("Delete this project? [y/N] ");
+                } else {
+                    print!("Type the project name to delete it anyway: ");
+                }
+                io::stdout().flush()?;
+
+                let mut input = String::new();
+                io::stdin().read_line(&mut input)?;
+                let confirmed = if warnings.is_empty() {
+                    matches!(input.trim(), "y" | "Y" | "yes")
+                } else {
+                    input.trim() == project.name
+                };
+                if !confirmed {
+                    say!("Cancelled");
+                    return Ok(());
+                }
+            }
+
+            if selector.delete_project(&project, true)? {
+                say!("✅ Deleted {} (`slop undo` brings it back)", project.name);
+            } else {
+                say!("✅ Deleted {} permanently (slop's trash is on another filesystem)", project.name);
+            }
+        }
         Some(Commands::Config { action }) => {
             match action {
                 None => {
-                    println!("📝 slop Configuration");
-                    println!();
-                    println!("Available commands:");
-                    println!("  slop config show                    # Show current config");
-                    println!("  slop config path <PATH>             # Set projects directory");
-                    println!("  slop config editor <COMMAND>        # Set editor command");
-                    println!("  slop config reset                   # Reset to defaults");
-                    println!();
-                    println!("Examples:");
-                    println!("  slop config editor claude           # Use Claude");
-                    println!("  slop config editor cursor           # Use Cursor");
-                    println!("  slop config editor \"code --wait\"    # VS Code with flags");
-                    println!("  slop config editor nvim             # Neovim");
-                    println!("  slop config path ~/dev/projects     # Custom projects path");
-                }
-                Some(ConfigAction::Show) => {
-                    let config = load_config(&get_config_file_path()?).unwrap_or_default();
-                    println!("📝 Configuration");
-                    println!();
-                    println!("Projects Path: {}", config.projects_path.display());
-                    println!("Editor:        {}", config.default_editor);
-                    println!();
-                    println!("Config file: {}", get_config_file_path()?.display());
+                    say!("📝 slop Configuration");
+                    say!();
+                    say!("Available commands:");
+                    say!("  slop config show                    # Show current config");
+                    say!("  slop config path <PATH>             # Set projects directory");
+                    say!("  slop config editor <COMMAND>        # Set editor command");
+                    say!("  slop config alias <NAME> [URL]      # Add or remove a repo alias");
+                    say!("  slop config edit                    # Open the config file in $EDITOR");
+                    say!("  slop config reset                   # Reset to defaults");
+                    say!();
+                    say!("Examples:");
+                    say!("  slop config editor claude           # Use Claude");
+                    say!("  slop config editor cursor           # Use Cursor");
+                    say!("  slop config editor \"code --wait\"    # VS Code with flags");
+                    say!("  slop config editor nvim             # Neovim");
+                    say!("  slop config path ~/dev/projects     # Custom projects path");
+                    say!("  slop config alias dotfiles me/dotfiles  # Type 'dotfiles' to clone");
+                }
+                Some(ConfigAction::Show { paths: true, .. }) => {
+                    print_search_order(&get_config_file_path()?);
+                }
+                Some(ConfigAction::Show { json, format, .. }) if json || format.as_deref() == Some("json") => {
+                    let config_path = get_config_file_path()?;
+                    let config = load_config(&config_path).unwrap_or_default();
+                    print_config_json(&config, &config_path)?;
+                }
+                Some(ConfigAction::Show { format, .. }) if format.as_deref() == Some("toml") => {
+                    let config_path = get_config_file_path()?;
+                    let config = load_config(&config_path).unwrap_or_default();
+                    print_config_toml(&config, &config_path);
+                }
+                Some(ConfigAction::Show { .. }) => {
+                    let config_path = get_config_file_path()?;
+                    let config = load_config(&config_path).unwrap_or_default();
+                    let roots = get_default_projects_roots();
+                    let source = projects_roots_source(&config_path);
+                    say!("📝 Configuration");
+                    say!();
+                    say!(
+                        "Projects Path: {} ({})",
+                        roots[0].display(),
+                        match source {
+                            "env" => "from slop_PATH",
+                            "file" => "from config file",
+                            _ => "default",
+                        }
+                    );
+                    for extra in &roots[1..] {
+                        say!("Also browsing: {}", extra.display());
+                    }
+                    let mut configured = vec![config.projects_path.clone()];
+                    configured.extend(config.projects_paths.iter().filter(|p| **p != config.projects_path).cloned());
+                    if source == "env"
+                        && configured != roots
+                        && config_file_keys(&config_path).iter().any(|k| k == "projects_path" || k == "projects_paths")
+                    {
+                        say_err!(
+                            "⚠️  slop_PATH overrides projects_path = \"{}\" from the config file (unset it to use the config)",
+                            config.projects_path.display()
+                        );
+                    }
+                    say!("Editor:        {}", config.default_editor);
+                    say!("Quiet:         {}", config.quiet);
+                    say!("Template:      {}", config.default_template.display_name());
+                    if !config.aliases.is_empty() {
+                        say!("Aliases:");
+                        for (name, url) in &config.aliases {
+                            say!("  {} -> {}", name, url);
+                        }
+                    }
+                    say!();
+                    say!("Config file: {}", config_path.display());
                 }
                 Some(ConfigAction::Path { path }) => {
                     let mut config = load_config(&get_config_file_path()?).unwrap_or_default();
                     config.projects_path = path.clone();
                     save_config(&config)?;
-                    println!("✅ Projects path set to: {}", path.display());
+                    say!("✅ Projects path set to: {}", path.display());
                 }
                 Some(ConfigAction::Editor { editor }) => {
                     let mut config = load_config(&get_config_file_path()?).unwrap_or_default();
                     config.default_editor = editor.clone();
                     save_config(&config)?;
-                    println!("✅ Default editor set to: {}", editor);
+                    say!("✅ Default editor set to: {}", editor);
+                }
+                Some(ConfigAction::Alias { name, url }) => {
+                    let mut config = load_config(&get_config_file_path()?).unwrap_or_default();
+                    match url {
+                        Some(url) => {
+                            config.aliases.insert(name.clone(), url.clone());
+                            save_config(&config)?;
+                            say!("✅ Alias {} -> {}", name, url);
+                        }
+                        None => {
+                            if config.aliases.remove(&name).is_none() {
+                                return Err(anyhow::anyhow!("No alias named '{}'", name));
+                            }
+                            save_config(&config)?;
+                            say!("✅ Removed alias {}", name);
+                        }
+                    }
+                }
+                Some(ConfigAction::Edit) => {
+                    let config_path = get_config_file_path()?;
+                    if !config_path.exists() {
+                        save_config(&load_config(&config_path).unwrap_or_default())?;
+                    }
+
+                    let config = load_config(&config_path).unwrap_or_default();
+                    let editor = config_file_editor(&config);
+                    let program = editor_program(&editor);
+                    let status = editor_command(&editor)
+                        .arg(&config_path)
+                        .status()
+                        .with_context(|| format!("Failed to launch {}", program))?;
+                    if !status.success() {
+                        return Err(anyhow::anyhow!("{} exited with {}", program, status));
+                    }
+
+                    let problems = check_config_file(&config_path)?;
+                    if problems.is_empty() {
+                        say!("✅ Config looks good");
+                    } else {
+                        say_err!("⚠️  {} has problems (run `slop config edit` again to fix):", config_path.display());
+                        for problem in &problems {
+                            say_err!("   {}", problem);
+                        }
+                    }
                 }
                 Some(ConfigAction::Reset) => {
                     let config = VibeConfig::default();
                     save_config(&config)?;
-                    println!("✅ Reset to defaults");
-                    println!("Projects Path: {}", config.projects_path.display());
-                    println!("Editor:        {}", config.default_editor);
+                    say!("✅ Reset to defaults");
+                    say!("Projects Path: {}", config.projects_path.display());
+                    say!("Editor:        {}", config.default_editor);
                 }
             }
         }
-        Some(Commands::Run { path, query }) => {
+        Some(Commands::Run { path, quiet, a11y, from_stdin, recurse_submodules, open, subdir, sparse, debug, editor, query }) => {
             let search_term = query.join(" ");
-            let projects_path = path.unwrap_or_else(get_default_projects_path);
-            let config = load_config(&get_config_file_path()?).unwrap_or_default();
+            let roots = path.map(|p| vec![p]).unwrap_or_else(get_default_projects_roots);
+            let mut config = load_config(&get_config_file_path()?).unwrap_or_default();
+            config.quiet |= quiet;
+            config.accessible |= a11y;
+            config.recurse_submodules |= recurse_submodules;
+            config.sparse_subdir |= sparse;
+            if let Some(editor) = editor {
+                config.default_editor = editor;
+            }
+
+            if from_stdin {
+                return run_batch(&roots, &config, io::stdin().lock());
+            }
+
+            // A path to an existing folder opens as-is rather than naming a new project
+            if let Some(dir) = existing_dir_argument(&search_term) {
+                let answer = if io::stdin().is_terminal() {
+                    print!("📂 {} is an existing folder. Open it in {}? [Y/n] ", dir.display(), config.default_editor);
+                    io::stdout().flush()?;
+                    let mut input = String::new();
+                    io::stdin().read_line(&mut input)?;
+                    input
+                } else {
+                    String::new()
+                };
+                if !matches!(answer.trim(), "n" | "N" | "no") {
+                    first_run_editor_setup(&mut config)?;
+                    if roots.iter().any(|root| dir.starts_with(root)) {
+                        update_access_time(&dir)?;
+                    } else {
+                        // Leave folders outside the roots untouched; only remember the visit
+                        record_access(&dir).ok();
+                    }
+                    log_activity(&config, "open", &dir, Some(&config.default_editor));
+                    return open_in_editor(&dir, &roots, &config);
+                }
+            }
             
-            let mut selector = VibeSelector::new(search_term, projects_path)?;
+            let mut selector = VibeSelector::new(search_term, roots.clone())?;
+            selector.config.accessible = config.accessible;
+            selector.show_score = debug;
+            selector.config.default_editor = config.default_editor.clone();
             let result = selector.run()?;
+            // Ctrl+E may have switched editors for this session
+            config.default_editor = selector.config.default_editor.clone();
 
             if let Some(result) = result {
+                if open && result.open {
+                    first_run_editor_setup(&mut config)?;
+                }
                 match result.action {
+                    SelectionAction::OpenExisting if result.workspace.len() > 1 => {
+                        for path in &result.workspace {
+                            update_access_time(path)?;
+                            log_activity(&config, "open", path, Some(&config.default_editor));
+                        }
+                        open_workspace(&result.workspace, &roots, &config)?;
+                    }
                     SelectionAction::OpenExisting => {
                         update_access_time(&result.path)?;
-                        open_in_editor(&result.path, &config)?;
+                        log_activity(&config, "open", &result.path, Some(&config.default_editor));
+                        open_in_editor(&result.path, &roots, &config)?;
                     }
                     SelectionAction::CreateNew => {
                         if let Some(template) = result.template {
-                            create_project_from_template(&result.path, &template)?;
+                            create_project_from_template(&result.path, &template, &config)?;
+                            record_last_action(&LastAction::Created(result.path.clone()))?;
                             update_access_time(&result.path)?;
-                            open_in_editor(&result.path, &config)?;
+                            log_activity(&config, "create", &result.path, Some(&config.default_editor));
+                            open_in_editor(&result.path, &roots, &config)?;
                         }
                     }
                     SelectionAction::CloneRepo => {
                         if let Some(url) = result.git_url {
-                            println!("🌐 Cloning {}...", url);
-                            clone_repository(&url, &result.path)?;
+                            if !config.quiet {
+                                say!("🌐 Cloning {}...", url);
+                            }
+                            let subdir = subdir.or(result.subdir);
+                            clone_repository(
+                                &url,
+                                &result.path,
+                                result.sparse_path.as_deref().or(subdir.as_deref().filter(|_| config.sparse_subdir)),
+                                config.quiet,
+                                config.recurse_submodules,
+                                config.clone_protocol,
+                                &config.mirrors,
+                            )?;
+                            checkout_ref(&result.path, result.git_ref.as_deref(), config.quiet);
+                            record_last_action(&LastAction::Cloned(result.path.clone()))?;
                             update_access_time(&result.path)?;
-                            open_in_editor(&result.path, &config)?;
+                            // A fresh clone may be untrusted code, so it can land in a shell instead
+                            let next = if open && result.open {
+                                config.after_clone.resolve(&config)?
+                            } else {
+                                AfterClone::Done
+                            };
+                            let opening = next == AfterClone::Editor;
+                            log_activity(&config, "clone", &result.path, opening.then_some(config.default_editor.as_str()));
+                            let open_path = if opening || next == AfterClone::Shell {
+                                clone_open_path(&result.path, subdir.as_deref())
+                            } else {
+                                result.path.clone()
+                            };
+                            if opening {
+                                open_in_editor(&open_path, &roots, &config)?;
+                            } else if next == AfterClone::Shell {
+                                open_shell(&open_path, &config)?;
+                            } else {
+                                if !config.quiet {
+                                    say!("📁 Cloned into {}", result.path.display());
+                                }
+                                if open && !result.open {
+                                    // Clone-only from the navigator goes straight back to it
+                                    relaunch_navigator(&roots, &config)?;
+                                }
+                            }
                         }
                     }
                 }
@@ -1692,4 +7395,287 @@ async fn main() -> Result<()> {
     }
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A fresh, empty directory under the system temp dir.
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("slop-test-{}-{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    /// A selector over an empty temp root, sized as if the terminal were `width` x `height`.
+    fn selector(name: &str, width: u16, height: u16) -> VibeSelector {
+        let mut selector = VibeSelector::new(String::new(), vec![temp_dir(name)]).unwrap();
+        selector.term_width = width;
+        selector.term_height = height;
+        selector
+    }
+
+    #[test]
+    fn editor_commands_split_into_program_and_args() {
+        assert_eq!(split_command_line("code --wait"), ["code", "--wait"]);
+        assert_eq!(split_command_line("  nvim  "), ["nvim"]);
+        assert_eq!(
+            split_command_line(r#""/opt/My Editor/edit" -n 'a b' c\ d"#),
+            ["/opt/My Editor/edit", "-n", "a b", "c d"]
+        );
+        assert_eq!(split_command_line(r#"edit """#), ["edit", ""]);
+        assert!(split_command_line("").is_empty());
+        assert_eq!(editor_program("code --wait"), "code");
+    }
+
+    #[test]
+    fn quoted_editor_survives_a_config_round_trip() {
+        let dir = temp_dir("config-round-trip");
+        let path = dir.join("config.toml");
+        let config = VibeConfig {
+            default_editor: r#""/opt/My Editor/edit" -n"#.to_string(),
+            favorite_editors: vec![r#""C:\Tools\ed.exe" --wait"#.to_string()],
+            ..Default::default()
+        };
+        fs::write(&path, config_toml(&config)).unwrap();
+
+        let loaded = load_config(&path).unwrap();
+        assert_eq!(loaded.default_editor, config.default_editor);
+        assert_eq!(loaded.favorite_editors, config.favorite_editors);
+        assert_eq!(split_command_line(&loaded.default_editor), ["/opt/My Editor/edit", "-n"]);
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn small_terminals_keep_rows_inside_the_screen() {
+        for (width, height) in [(40, 10), (20, 5), (1, 1)] {
+            let selector = selector("small-terminal", width, height);
+            for used in 0..width as usize + 5 {
+                let fitted = selector.fit(&"x".repeat(200), used);
+                assert!(!fitted.is_empty());
+                if used < width as usize {
+                    assert!(used + fitted.chars().count() <= width as usize);
+                }
+            }
+            for chrome in 0..12 {
+                let (rows, footer) = selector.list_layout(chrome);
+                assert!(rows >= 1, "no project row at {}x{}", width, height);
+                if footer {
+                    assert!(chrome + rows + 2 <= height as usize);
+                }
+            }
+            fs::remove_dir_all(&selector.base_path).ok();
+        }
+    }
+
+    #[test]
+    fn default_project_name_takes_the_first_free_suffix() {
+        let root = temp_dir("default-name");
+        let config = VibeConfig { default_project_name: "scratch".to_string(), ..Default::default() };
+        assert_eq!(resolve_default_project_name(&config, &root), "scratch");
+
+        fs::create_dir(root.join("scratch")).unwrap();
+        assert_eq!(resolve_default_project_name(&config, &root), "scratch-2");
+
+        fs::create_dir(root.join("scratch-2")).unwrap();
+        assert_eq!(resolve_default_project_name(&config, &root), "scratch-3");
+
+        // Gaps left by deleted projects get reused
+        fs::create_dir(root.join("scratch-4")).unwrap();
+        fs::remove_dir(root.join("scratch-2")).unwrap();
+        assert_eq!(suffixed_project_path(&root.join("scratch")), root.join("scratch-2"));
+
+        let invalid = VibeConfig { default_project_name: "a/b".to_string(), ..Default::default() };
+        assert_eq!(resolve_default_project_name(&invalid, &root), DEFAULT_PROJECT_NAME);
+        fs::remove_dir_all(&root).ok();
+    }
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn keymap_resolves_bound_and_unbound_keys() {
+        let keymap = Keymap::new(&BTreeMap::new());
+        let ctrl_p = key(KeyCode::Char('p'), KeyModifiers::CONTROL);
+        assert_eq!(keymap.action(&ctrl_p, false), Some(KeyAction::Up));
+        assert_eq!(keymap.action(&ctrl_p, true), Some(KeyAction::Up));
+        assert_eq!(keymap.action(&key(KeyCode::F(9), KeyModifiers::NONE), false), None);
+
+        // Esc clears a search first and quits once it's empty
+        let esc = key(KeyCode::Esc, KeyModifiers::NONE);
+        assert_eq!(keymap.action(&esc, true), Some(KeyAction::Clear));
+        assert_eq!(keymap.action(&esc, false), Some(KeyAction::Quit));
+
+        // A bare letter is a command only until something is typed
+        let d = key(KeyCode::Char('d'), KeyModifiers::NONE);
+        assert_eq!(keymap.action(&d, false), Some(KeyAction::Delete));
+        assert_eq!(keymap.action(&d, true), None);
+        for modifiers in [KeyModifiers::CONTROL, KeyModifiers::ALT] {
+            let modified = key(KeyCode::Char('d'), modifiers);
+            assert_eq!(keymap.action(&modified, true), None);
+            assert_eq!(keymap.action(&modified, false), None);
+        }
+        assert_eq!(keymap.action(&key(KeyCode::Char('d'), KeyModifiers::SHIFT), false), Some(KeyAction::Delete));
+
+        let overrides = BTreeMap::from([("delete".to_string(), vec!["ctrl+x".to_string()])]);
+        let keymap = Keymap::new(&overrides);
+        assert_eq!(keymap.action(&d, false), None);
+        assert_eq!(keymap.action(&key(KeyCode::Char('x'), KeyModifiers::CONTROL), true), Some(KeyAction::Delete));
+    }
+
+    #[test]
+    fn text_keys_are_plain_characters_while_typing() {
+        let a = key(KeyCode::Char('a'), KeyModifiers::NONE);
+        let shift_a = key(KeyCode::Char('A'), KeyModifiers::SHIFT);
+        let ctrl_a = key(KeyCode::Char('a'), KeyModifiers::CONTROL);
+        let enter = key(KeyCode::Enter, KeyModifiers::NONE);
+        assert!(is_text_key(&a, true));
+        assert!(is_text_key(&shift_a, true));
+        assert!(!is_text_key(&a, false));
+        assert!(!is_text_key(&ctrl_a, true));
+        assert!(!is_text_key(&enter, true));
+    }
+
+    #[test]
+    fn pasted_urls_keep_query_strings() {
+        let url = "https://github.com/owner/repo?a=b&c=d%20e#readme";
+        assert_eq!(sanitize_paste(&format!("  {}\r\n", url)), url);
+        assert_eq!(sanitize_paste("owner/\u{1b}repo\t"), "owner/repo");
+    }
+
+    #[test]
+    fn every_bound_action_is_in_the_help_table() {
+        let selector = selector("help-table", 80, 24);
+        let shortcuts = selector.shortcuts();
+        for action in KeyAction::ALL {
+            for key in selector.keymap.keys(action) {
+                assert!(
+                    shortcuts.iter().any(|s| s.keys.iter().any(|k| k.contains(&key))),
+                    "{} ({}) is missing from the help table",
+                    action.name(),
+                    key
+                );
+            }
+        }
+        assert!(shortcuts.iter().any(|s| s.keys == ["Ctrl+S"]));
+        fs::remove_dir_all(&selector.base_path).ok();
+    }
+
+    #[test]
+    fn github_browser_links_split_into_repo_subdir_and_ref() {
+        let repo = "https://github.com/vercel/next.js".to_string();
+        assert_eq!(
+            split_repo_subdir("https://github.com/vercel/next.js/tree/canary/packages/next"),
+            (repo.clone(), Some("packages/next".to_string()), Some("canary".to_string()))
+        );
+        assert_eq!(
+            split_repo_subdir("https://github.com/vercel/next.js/blob/v14.0.0/packages/next/package.json"),
+            (repo.clone(), Some("packages/next".to_string()), Some("v14.0.0".to_string()))
+        );
+        assert_eq!(
+            split_repo_subdir("https://github.com/vercel/next.js/tree/canary"),
+            (repo.clone(), None, Some("canary".to_string()))
+        );
+        assert_eq!(
+            split_repo_subdir("https://github.com/vercel/next.js/packages/next"),
+            (repo.clone(), Some("packages/next".to_string()), None)
+        );
+        assert_eq!(split_repo_subdir(&repo), (repo.clone(), None, None));
+    }
+
+    #[test]
+    fn duplicate_notes_combine_root_and_remote() {
+        let mut selector = selector("duplicates", 80, 24);
+        let project = |root: &str, name: &str| Project {
+            name: name.to_string(),
+            path: PathBuf::from(root).join(name),
+            root: PathBuf::from(root),
+            last_accessed: Utc::now(),
+            created: Utc::now(),
+            score: 0.0,
+            project_type: ProjectType::GitRepo,
+            description: None,
+        };
+        let projects = vec![project("/home/work", "app"), project("/home/play", "app"), project("/home/play", "app-2")];
+        for p in &projects {
+            selector.project_remotes.insert(p.path.clone(), Some("https://github.com/o/app".to_string()));
+        }
+        selector.all_projects = Some(projects);
+
+        let notes = selector.duplicate_notes();
+        assert_eq!(notes[&PathBuf::from("/home/work/app")], "in work, same repo as app, app-2");
+        assert_eq!(notes[&PathBuf::from("/home/play/app-2")], "same repo as app, app");
+        fs::remove_dir_all(&selector.base_path).ok();
+    }
+
+    #[test]
+    fn init_scripts_pass_every_subcommand_through() {
+        use clap::CommandFactory;
+
+        for command in Cli::command().get_subcommands() {
+            let name = command.get_name();
+            assert!(name == "run" || PASSTHROUGH_COMMANDS.contains(&name), "`slop {}` would be run as a search", name);
+        }
+        assert!(PASSTHROUGH_COMMANDS.contains(&"new"));
+    }
+
+    #[test]
+    fn auth_failures_need_git_wording_not_just_digits() {
+        assert!(is_auth_failure("fatal: unable to access 'https://github.com/a/b/': The requested URL returned error: 403"));
+        assert!(is_auth_failure("git@github.com: Permission denied (publickey)."));
+        assert!(!is_auth_failure("Cloning into 'app403'...\nfatal: early EOF"));
+    }
+
+    #[test]
+    fn dev_command_falls_through_a_broken_package_json() {
+        let dir = temp_dir("dev-command");
+        fs::write(dir.join("package.json"), "{ not json").unwrap();
+        fs::write(dir.join("Cargo.toml"), "").unwrap();
+        assert_eq!(detect_dev_command(&dir).as_deref(), Some("cargo run"));
+
+        fs::write(dir.join("package.json"), r#"{"scripts": {"build": "tsc"}}"#).unwrap();
+        assert_eq!(detect_dev_command(&dir).as_deref(), Some("cargo run"));
+
+        fs::write(dir.join("package.json"), r#"{"scripts": {"dev": "vite"}}"#).unwrap();
+        fs::write(dir.join("pnpm-lock.yaml"), "").unwrap();
+        assert_eq!(detect_dev_command(&dir).as_deref(), Some("pnpm run dev"));
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn headless_selection_needs_exactly_one_name_match() {
+        let mut selector = selector("headless", 80, 24);
+        let project = |root: &str| Project {
+            name: "app".to_string(),
+            path: PathBuf::from(root).join("app"),
+            root: PathBuf::from(root),
+            last_accessed: Utc::now(),
+            created: Utc::now(),
+            score: 0.0,
+            project_type: ProjectType::Local,
+            description: None,
+        };
+        selector.input_buffer = "app".to_string();
+        selector.all_projects = Some(vec![project("/home/work")]);
+        let picked = selector.headless_selection().unwrap();
+        assert_eq!(picked.map(|result| result.path), Some(PathBuf::from("/home/work/app")));
+
+        selector.all_projects = Some(vec![project("/home/work"), project("/home/play")]);
+        assert!(selector.headless_selection().unwrap().is_none());
+        fs::remove_dir_all(&selector.base_path).ok();
+    }
+
+    #[test]
+    fn headless_clone_explains_a_destination_choice() {
+        let mut selector = selector("headless-clone", 80, 24);
+        selector.roots.push(env::temp_dir());
+        selector.pick_destination = true;
+        selector.input_buffer = "owner/repo".to_string();
+        let err = selector.headless_selection().unwrap_err();
+        assert!(err.to_string().contains("any of 2 roots"));
+        fs::remove_dir_all(&selector.base_path).ok();
+    }
+}
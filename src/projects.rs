@@ -0,0 +1,1085 @@
+//! Project discovery and ranking, independent of the terminal UI.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use std::{
+    collections::HashMap,
+    fs::{self, Metadata},
+    io::{self, BufRead, BufReader},
+    path::{Path, PathBuf},
+    process::Command,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+#[derive(Debug, Clone)]
+pub struct Project {
+    pub name: String,
+    pub path: PathBuf,
+    pub root: PathBuf,
+    pub last_accessed: DateTime<Utc>,
+    pub created: DateTime<Utc>,
+    pub score: f64,
+    pub project_type: ProjectType,
+    /// One line from `.slop` or the README, shown next to the name
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum ProjectType {
+    Local,
+    GitRepo,
+}
+
+/// What a scan should do beyond listing directories.
+#[derive(Debug, Clone, Default)]
+pub struct ScanOptions {
+    /// Where to cache deep mtimes; `None` disables deep mtime scanning
+    pub deep_mtime_cache: Option<PathBuf>,
+    /// Where to cache whether each project is a git repo; `None` stats `.git` every scan
+    pub git_cache: Option<PathBuf>,
+    /// Directories never descended into when walking inside projects
+    pub ignore_dirs: Vec<String>,
+    /// How many levels below a root to look for projects (1 = direct children only)
+    pub scan_depth: usize,
+    /// Also list git repos found anywhere under a directory (usually HOME)
+    pub discovery: Option<Discovery>,
+}
+
+/// Where to look for scattered git repos and where the list of them is cached.
+#[derive(Debug, Clone)]
+pub struct Discovery {
+    pub dir: PathBuf,
+    pub max_depth: usize,
+    pub cache: PathBuf,
+}
+
+/// Files or folders that mark a directory as a project when scanning nested layouts.
+const PROJECT_MARKERS: &[&str] = &[
+    ".git",
+    "Cargo.toml",
+    "package.json",
+    "pyproject.toml",
+    "requirements.txt",
+    "go.mod",
+    "dune-project",
+    "gleam.toml",
+    "pubspec.yaml",
+    "Dockerfile",
+    ".devcontainer",
+    "Makefile",
+    "README.md",
+];
+
+pub fn check_base_path(base_path: &Path) -> Result<()> {
+    // A symlinked projects directory (e.g. on an external drive) may point nowhere
+    if let Ok(link_metadata) = fs::symlink_metadata(base_path) {
+        if link_metadata.file_type().is_symlink() && fs::metadata(base_path).is_err() {
+            return Err(anyhow::anyhow!(
+                "projects directory unavailable (symlink target missing): {}",
+                base_path.display()
+            ));
+        }
+    }
+
+    match fs::metadata(base_path) {
+        Ok(metadata) if !metadata.is_dir() => Err(anyhow::anyhow!(
+            "projects path is a file, not a directory: {} (point projects_path or --path at a folder)",
+            base_path.display()
+        )),
+        Ok(_) => match fs::read_dir(base_path) {
+            Err(e) if e.kind() == io::ErrorKind::PermissionDenied => Err(permission_denied(base_path)),
+            _ => Ok(()),
+        },
+        Err(e) if e.kind() == io::ErrorKind::PermissionDenied => Err(permission_denied(base_path)),
+        // Missing is fine; ensure_roots creates it
+        Err(_) => Ok(()),
+    }
+}
+
+fn permission_denied(path: &Path) -> anyhow::Error {
+    anyhow::anyhow!(
+        "permission denied reading projects directory {} (check its owner and mode with `ls -ld {}`)",
+        path.display(),
+        path.display()
+    )
+}
+
+pub fn ensure_roots(roots: &[PathBuf]) -> Result<()> {
+    for root in roots {
+        check_base_path(root)?;
+
+        // Create base directory if it doesn't exist
+        match fs::create_dir_all(root) {
+            Err(e) if e.kind() == io::ErrorKind::PermissionDenied => {
+                return Err(anyhow::anyhow!(
+                    "permission denied creating projects directory {} (create it yourself, or choose another with `slop config path`)",
+                    root.display()
+                ));
+            }
+            result => result.with_context(|| format!("Failed to create base directory: {}", root.display()))?,
+        }
+    }
+    Ok(())
+}
+
+/// Refuse to delete anything but a project inside a projects root, at most
+/// `scan_depth` levels down.
+pub fn check_deletable(path: &Path, roots: &[PathBuf], scan_depth: usize) -> Result<()> {
+    let canonical = |p: &Path| fs::canonicalize(p).unwrap_or_else(|_| p.to_path_buf());
+    // Canonicalize the parent only, so a symlinked project still counts as the link
+    let resolved = match (path.parent(), path.file_name()) {
+        (Some(parent), Some(name)) => canonical(parent).join(name),
+        _ => return Err(anyhow::anyhow!("Refusing to delete {}: not a project directory", path.display())),
+    };
+
+    if let Some(home) = dirs::home_dir() {
+        if resolved == canonical(&home) || canonical(&resolved) == canonical(&home) {
+            return Err(anyhow::anyhow!("Refusing to delete your home directory: {}", path.display()));
+        }
+    }
+
+    for root in roots {
+        if resolved == canonical(root) || canonical(&resolved) == canonical(root) {
+            return Err(anyhow::anyhow!("Refusing to delete a projects root: {}", path.display()));
+        }
+    }
+
+    let inside_root = roots.iter().any(|root| {
+        resolved
+            .strip_prefix(canonical(root))
+            .is_ok_and(|relative| (1..=scan_depth.max(1)).contains(&relative.components().count()))
+    });
+    if !inside_root {
+        return Err(anyhow::anyhow!(
+            "Refusing to delete {}: not a project inside a projects root",
+            path.display()
+        ));
+    }
+
+    Ok(())
+}
+
+/// Describe work that deleting `project` would lose; empty when it looks safe.
+pub fn unsaved_work(project: &Project) -> Vec<String> {
+    let mut warnings = Vec::new();
+
+    if let ProjectType::GitRepo = project.project_type {
+        let git = |args: &[&str]| {
+            Command::new("git")
+                .arg("-C")
+                .arg(&project.path)
+                .args(args)
+                .output()
+                .ok()
+                .filter(|output| output.status.success())
+                .map(|output| String::from_utf8_lossy(&output.stdout).into_owned())
+        };
+
+        match git(&["status", "--porcelain"]) {
+            Some(status) => {
+                let changed: Vec<&str> = status.lines().collect();
+                if !changed.is_empty() {
+                    warnings.push(format!("{} uncommitted change(s):", changed.len()));
+                    for line in changed.iter().take(5) {
+                        warnings.push(format!("    {}", line));
+                    }
+                    if changed.len() > 5 {
+                        warnings.push(format!("    ... and {} more", changed.len() - 5));
+                    }
+                }
+            }
+            None => warnings.push("Could not read git status".to_string()),
+        }
+
+        // Commits on local branches that no remote has seen
+        if let Some(log) = git(&["log", "--branches", "--not", "--remotes", "--oneline"]) {
+            let unpushed = log.lines().count();
+            if unpushed > 0 {
+                warnings.push(format!("{} unpushed commit(s)", unpushed));
+            }
+        }
+    } else if Utc::now().signed_duration_since(project.last_accessed).num_hours() < 24 {
+        warnings.push("Not under git and modified in the last 24 hours".to_string());
+    }
+
+    warnings
+}
+
+/// Scan every root for project directories, independent of the TUI.
+pub fn scan_projects(roots: &[PathBuf], options: &ScanOptions) -> Result<Vec<Project>> {
+    let mut projects = Vec::new();
+    scan_projects_with(roots, options, |project| projects.push(project))?;
+    Ok(projects)
+}
+
+/// Like `scan_projects`, but hands over each project as soon as it's found so a
+/// slow (e.g. network) root doesn't hold up the first render.
+pub fn scan_projects_with(roots: &[PathBuf], options: &ScanOptions, mut found: impl FnMut(Project)) -> Result<()> {
+    // Directory mtimes miss edits deep inside a project; fold in the newest file mtime
+    let mut cache = options.deep_mtime_cache.as_deref().map(DeepMtimeCache::load);
+    let mut git_cache = options.git_cache.as_deref().map(GitPresenceCache::load);
+
+    let mut with_deep_mtime = |mut project: Project| {
+        if let Some(cache) = &mut cache {
+            if let Some(newest) = cache.newest(&project.path, &options.ignore_dirs) {
+                project.last_accessed = project.last_accessed.max(newest);
+            }
+        }
+        project.description = read_description(&project.path);
+        found(project);
+    };
+
+    for root in roots {
+        scan_root(root, options, &mut git_cache, &mut with_deep_mtime)?;
+    }
+
+    if let Some(discovery) = &options.discovery {
+        // Repos under a root were listed above already
+        for path in discovered_repos(discovery, &options.ignore_dirs) {
+            if roots.iter().any(|root| path.starts_with(root)) {
+                continue;
+            }
+            let Ok(metadata) = fs::metadata(&path) else {
+                continue;
+            };
+            let (created, last_accessed) = get_times(&metadata)?;
+            with_deep_mtime(Project {
+                name: path.strip_prefix(&discovery.dir).unwrap_or(&path).to_string_lossy().to_string(),
+                path: path.clone(),
+                root: discovery.dir.clone(),
+                last_accessed,
+                created,
+                score: 0.0,
+                project_type: ProjectType::GitRepo,
+                description: None,
+            });
+        }
+    }
+
+    if let Some(cache) = cache {
+        cache.save().ok();
+    }
+    if let Some(git_cache) = git_cache {
+        git_cache.save().ok();
+    }
+    Ok(())
+}
+
+fn scan_root(
+    root: &Path,
+    options: &ScanOptions,
+    git_cache: &mut Option<GitPresenceCache>,
+    found: &mut dyn FnMut(Project),
+) -> Result<()> {
+    check_base_path(root)?;
+
+    let slopignore = load_slopignore(root);
+    scan_dir(root, root, 1, options, &slopignore, git_cache, found)
+}
+
+fn scan_dir(
+    root: &Path,
+    dir: &Path,
+    depth: usize,
+    options: &ScanOptions,
+    slopignore: &Gitignore,
+    git_cache: &mut Option<GitPresenceCache>,
+    found: &mut dyn FnMut(Project),
+) -> Result<()> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        // An unreadable folder deep inside a root shouldn't hide everything else
+        Err(_) if depth > 1 => return Ok(()),
+        Err(e) => return Err(e).with_context(|| format!("Failed to read directory: {}", dir.display())),
+    };
+
+    for entry in entries {
+        let entry = entry?;
+        let path = entry.path();
+
+        if path.is_dir() && !slopignore.matched(&path, true).is_ignore() {
+            // Non-UTF-8 names are legal on Linux; show them lossily but keep the real path
+            if let Some(name) = path.file_name().map(|n| n.to_string_lossy()) {
+                if depth > 1 && (name.starts_with('.') || options.ignore_dirs.iter().any(|d| *d == name)) {
+                    continue;
+                }
+
+                // Symlinked projects keep the link name but report the target's metadata
+                let (metadata, resolved) = if entry.file_type()?.is_symlink() {
+                    match (fs::metadata(&path), fs::canonicalize(&path)) {
+                        (Ok(metadata), Ok(resolved)) => (metadata, resolved),
+                        _ => continue,
+                    }
+                } else {
+                    (entry.metadata()?, path.clone())
+                };
+
+                // Grouping folders (no marker, but subfolders) are walked into instead
+                if depth < options.scan_depth
+                    && !is_project_dir(&resolved)
+                    && has_subdirs(&resolved, &options.ignore_dirs)
+                {
+                    scan_dir(root, &path, depth + 1, options, slopignore, git_cache, found)?;
+                    continue;
+                }
+
+                let (created, last_accessed) = get_times(&metadata)?;
+
+                // Check if it's a git repo
+                let is_git = match git_cache {
+                    Some(cache) => cache.is_git_repo(&resolved, &metadata),
+                    None => resolved.join(".git").exists(),
+                };
+                let project_type = if is_git {
+                    ProjectType::GitRepo
+                } else {
+                    ProjectType::Local
+                };
+
+                // Nested projects are named by their path below the root
+                let name = match path.strip_prefix(root) {
+                    Ok(relative) if depth > 1 => relative.to_string_lossy().to_string(),
+                    _ => name.to_string(),
+                };
+
+                found(Project {
+                    name,
+                    path: path.clone(),
+                    root: root.to_path_buf(),
+                    last_accessed,
+                    created,
+                    score: 0.0,
+                    project_type,
+                    description: None,
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// A `key = "value"` line from the project's `.slop` file.
+pub fn slop_file_value(path: &Path, key: &str) -> Option<String> {
+    let content = fs::read_to_string(path.join(".slop")).ok()?;
+    content.lines().find_map(|line| {
+        let (name, value) = line.split_once('=')?;
+        (name.trim() == key).then(|| value.trim().trim_matches('"').to_string())
+    })
+}
+
+/// A `description = "..."` line in the project's `.slop` file, or else the first
+/// line of prose in its README (headings, badges and HTML are skipped).
+fn read_description(path: &Path) -> Option<String> {
+    if let Some(description) = slop_file_value(path, "description") {
+        return Some(description).filter(|d| !d.is_empty());
+    }
+
+    let readme = fs::File::open(path.join("README.md")).ok()?;
+    let mut in_code = false;
+    BufReader::new(readme)
+        .lines()
+        .take(40)
+        .map_while(Result::ok)
+        .find_map(|line| {
+            let line = line.trim();
+            if line.starts_with("```") {
+                in_code = !in_code;
+                return None;
+            }
+            let skip = in_code
+                || line.is_empty()
+                || line.starts_with(['#', '<', '!', '[', '|', '>'])
+                || line.chars().all(|c| c == '=' || c == '-');
+            (!skip).then(|| line.chars().take(200).collect())
+        })
+}
+
+/// Projects from the last complete scan, so the navigator can list them before
+/// slow roots have been walked again.
+pub struct ProjectIndex {
+    pub projects: Vec<Project>,
+    pub updated: DateTime<Utc>,
+}
+
+/// The index at `path`, if it was built for exactly these roots.
+pub fn load_index(path: &Path, roots: &[PathBuf]) -> Option<ProjectIndex> {
+    let index: serde_json::Value = serde_json::from_str(&fs::read_to_string(path).ok()?).ok()?;
+    let indexed_roots: Vec<PathBuf> = index["roots"].as_array()?.iter().filter_map(|r| r.as_str().map(PathBuf::from)).collect();
+    if indexed_roots != roots {
+        return None;
+    }
+
+    let time = |value: &serde_json::Value| value.as_i64().and_then(|secs| DateTime::from_timestamp(secs, 0));
+    let projects = index["projects"]
+        .as_array()?
+        .iter()
+        .filter_map(|p| {
+            Some(Project {
+                name: p["name"].as_str()?.to_string(),
+                path: index_path_bytes(&p["path_bytes"]).unwrap_or(PathBuf::from(p["path"].as_str()?)),
+                root: PathBuf::from(p["root"].as_str()?),
+                last_accessed: time(&p["last_accessed"])?,
+                created: time(&p["created"])?,
+                score: 0.0,
+                project_type: if p["git"].as_bool()? { ProjectType::GitRepo } else { ProjectType::Local },
+                description: p["description"].as_str().map(str::to_string),
+            })
+        })
+        // Deleted since the index was written
+        .filter(|p| p.path.is_dir())
+        .collect();
+
+    Some(ProjectIndex {
+        projects,
+        updated: time(&index["updated"])?,
+    })
+}
+
+pub fn save_index(path: &Path, roots: &[PathBuf], projects: &[Project]) -> Result<()> {
+    let projects: Vec<serde_json::Value> = projects
+        .iter()
+        .map(|p| {
+            serde_json::json!({
+                "name": p.name,
+                "path": p.path.to_string_lossy(),
+                "root": p.root.to_string_lossy(),
+                "last_accessed": p.last_accessed.timestamp(),
+                "created": p.created.timestamp(),
+                "git": matches!(p.project_type, ProjectType::GitRepo),
+                "description": p.description,
+                "path_bytes": non_utf8_path_bytes(&p.path),
+            })
+        })
+        .collect();
+    let index = serde_json::json!({
+        "updated": Utc::now().timestamp(),
+        "roots": roots.iter().map(|root| root.to_string_lossy()).collect::<Vec<_>>(),
+        "projects": projects,
+    });
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, serde_json::to_string(&index)?)?;
+    Ok(())
+}
+
+/// Raw bytes for a path JSON can't hold as a string, so the index round-trips it.
+#[cfg(unix)]
+fn non_utf8_path_bytes(path: &Path) -> Option<Vec<u8>> {
+    use std::os::unix::ffi::OsStrExt;
+    path.to_str().is_none().then(|| path.as_os_str().as_bytes().to_vec())
+}
+
+#[cfg(not(unix))]
+fn non_utf8_path_bytes(_path: &Path) -> Option<Vec<u8>> {
+    None
+}
+
+#[cfg(unix)]
+fn index_path_bytes(value: &serde_json::Value) -> Option<PathBuf> {
+    use std::os::unix::ffi::OsStrExt;
+    let bytes: Vec<u8> = value.as_array()?.iter().map(|b| b.as_u64().map(|b| b as u8)).collect::<Option<_>>()?;
+    Some(PathBuf::from(std::ffi::OsStr::from_bytes(&bytes)))
+}
+
+#[cfg(not(unix))]
+fn index_path_bytes(_value: &serde_json::Value) -> Option<PathBuf> {
+    None
+}
+
+/// The cached list of discovered repos, walking for it only when there's no cache yet.
+fn discovered_repos(discovery: &Discovery, ignore_dirs: &[String]) -> Vec<PathBuf> {
+    match fs::read_to_string(&discovery.cache) {
+        Ok(content) => content.lines().map(PathBuf::from).filter(|path| path.is_dir()).collect(),
+        Err(_) => reindex(discovery, ignore_dirs).unwrap_or_default(),
+    }
+}
+
+/// Walk `discovery.dir` for git repos and rewrite the cache. Hidden folders,
+/// `ignore_dirs` and `.slopignore` patterns in that directory are skipped, and
+/// repos aren't searched for nested ones.
+pub fn reindex(discovery: &Discovery, ignore_dirs: &[String]) -> Result<Vec<PathBuf>> {
+    let slopignore = load_slopignore(&discovery.dir);
+    let mut repos = Vec::new();
+    find_repos(&discovery.dir, discovery.max_depth, ignore_dirs, &slopignore, &mut repos);
+    repos.sort();
+
+    if let Some(parent) = discovery.cache.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let content: String = repos.iter().map(|path| format!("{}\n", path.display())).collect();
+    fs::write(&discovery.cache, content)?;
+    Ok(repos)
+}
+
+fn find_repos(dir: &Path, depth: usize, ignore_dirs: &[String], slopignore: &Gitignore, repos: &mut Vec<PathBuf>) {
+    if depth == 0 {
+        return;
+    }
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        // Symlinks are skipped so the walk can't loop
+        if !entry.file_type().is_ok_and(|t| t.is_dir())
+            || name.starts_with('.')
+            || ignore_dirs.contains(&name)
+            || slopignore.matched(entry.path(), true).is_ignore()
+        {
+            continue;
+        }
+
+        let path = entry.path();
+        if path.join(".git").exists() {
+            repos.push(path);
+        } else {
+            find_repos(&path, depth - 1, ignore_dirs, slopignore, repos);
+        }
+    }
+}
+
+fn is_project_dir(dir: &Path) -> bool {
+    PROJECT_MARKERS.iter().any(|marker| dir.join(marker).exists())
+}
+
+fn has_subdirs(dir: &Path, ignore_dirs: &[String]) -> bool {
+    fs::read_dir(dir).is_ok_and(|entries| {
+        entries.flatten().any(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            !name.starts_with('.') && !ignore_dirs.contains(&name) && entry.path().is_dir()
+        })
+    })
+}
+
+/// Gitignore-style patterns in `<root>/.slopignore` naming directories that aren't projects.
+fn load_slopignore(root: &Path) -> Gitignore {
+    let path = root.join(".slopignore");
+    if !path.is_file() {
+        return Gitignore::empty();
+    }
+    let mut builder = GitignoreBuilder::new(root);
+    // A bad pattern only drops that line
+    let _ = builder.add(path);
+    builder.build().unwrap_or_else(|_| Gitignore::empty())
+}
+
+fn get_times(metadata: &Metadata) -> Result<(DateTime<Utc>, DateTime<Utc>)> {
+    let created = metadata
+        .created()
+        .or_else(|_| metadata.modified())
+        .unwrap_or(UNIX_EPOCH);
+    let last_accessed = metadata.modified().unwrap_or(UNIX_EPOCH);
+
+    let created = DateTime::from(created);
+    let last_accessed = DateTime::from(last_accessed);
+
+    Ok((created, last_accessed))
+}
+
+pub fn is_name_separator(ch: char) -> bool {
+    matches!(ch, '-' | '_' | '.' | ' ' | '/')
+}
+
+/// Score every project against `query`, dropping non-matches when a query is given,
+/// best first. Projects named in `pinned` float above the rest.
+pub fn rank_projects(projects: &[Project], query: &str, pinned: &[String]) -> Vec<Project> {
+    let mut scored_projects: Vec<Project> = projects
+        .iter()
+        .map(|project| {
+            let score = calculate_score(
+                &project.name,
+                query,
+                &project.created,
+                &project.last_accessed,
+            );
+            let mut project = project.clone();
+            project.score = score;
+            project
+        })
+        .collect();
+
+    // Filter and sort
+    if !query.is_empty() {
+        scored_projects.retain(|p| p.score > 0.0);
+    }
+    let is_pinned = |p: &Project| pinned.contains(&p.name);
+    scored_projects.sort_by(|a, b| {
+        is_pinned(b)
+            .cmp(&is_pinned(a))
+            .then_with(|| b.score.partial_cmp(&a.score).unwrap())
+    });
+
+    scored_projects
+}
+
+pub fn calculate_score(
+    text: &str,
+    query: &str,
+    created: &DateTime<Utc>,
+    last_accessed: &DateTime<Utc>,
+) -> f64 {
+    score_breakdown(text, query, created, last_accessed).total
+}
+
+/// The parts `calculate_score` adds up, for the navigator's score overlay.
+#[derive(Debug, Clone, Default)]
+pub struct ScoreBreakdown {
+    /// Indices into `text`'s lowercased chars that the query matched
+    pub matched: Vec<usize>,
+    /// Match points after the density and length adjustments
+    pub match_score: f64,
+    pub created_bonus: f64,
+    pub access_bonus: f64,
+    /// 0 when the query doesn't match at all
+    pub total: f64,
+}
+
+pub fn score_breakdown(
+    text: &str,
+    query: &str,
+    created: &DateTime<Utc>,
+    last_accessed: &DateTime<Utc>,
+) -> ScoreBreakdown {
+    let mut breakdown = ScoreBreakdown::default();
+    let mut score = 0.0;
+
+    // Search query matching
+    if !query.is_empty() {
+        // Separators are optional when matching ("myapp" finds "my-app"), but
+        // each one still marks the start of a word for the boundary bonus
+        let mut text_chars: Vec<(char, bool, usize)> = Vec::new();
+        let mut at_boundary = true;
+        for (idx, ch) in text.to_lowercase().chars().enumerate() {
+            if is_name_separator(ch) {
+                at_boundary = true;
+                continue;
+            }
+            text_chars.push((ch, at_boundary, idx));
+            at_boundary = !ch.is_alphanumeric();
+        }
+        let query_chars: Vec<char> = query
+            .to_lowercase()
+            .chars()
+            .filter(|ch| !is_name_separator(*ch))
+            .collect();
+
+        let mut last_pos = -1i32;
+        let mut query_idx = 0;
+
+        for (pos, (ch, is_word_start, idx)) in text_chars.iter().enumerate() {
+            if query_idx >= query_chars.len() {
+                break;
+            }
+            if *ch != query_chars[query_idx] {
+                continue;
+            }
+
+            // Base point + word boundary bonus
+            score += 1.0;
+            if *is_word_start {
+                score += 1.0;
+            }
+
+            // Proximity bonus
+            if last_pos >= 0 {
+                let gap = pos as i32 - last_pos - 1;
+                score += 1.0 / (gap as f64 + 1.0).sqrt();
+            }
+
+            last_pos = pos as i32;
+            query_idx += 1;
+            breakdown.matched.push(*idx);
+        }
+
+        // Return 0 if not all query chars matched
+        if query_idx < query_chars.len() {
+            breakdown.matched.clear();
+            return breakdown;
+        }
+
+        // Density bonus; matches sit at distinct positions so this is at most 1,
+        // but clamp it so a change to the matcher can't inflate short names
+        if last_pos >= 0 {
+            score *= (query_chars.len() as f64 / (last_pos as f64 + 1.0)).min(1.0);
+        }
+
+        // Length penalty
+        score *= 10.0 / (text_chars.len() as f64 + 10.0);
+    }
+    breakdown.match_score = score;
+
+    // Time-based scoring
+    let now = Utc::now();
+
+    // Creation time bonus
+    let days_old = (now - *created).num_seconds() as f64 / 86400.0;
+    breakdown.created_bonus = 2.0 / (days_old + 1.0).sqrt();
+
+    // Access time bonus (most important)
+    let hours_since_access = (now - *last_accessed).num_seconds() as f64 / 3600.0;
+    breakdown.access_bonus = 5.0 / (hours_since_access + 1.0).sqrt();
+
+    breakdown.total = breakdown.match_score + breakdown.created_bonus + breakdown.access_bonus;
+    breakdown
+}
+
+const DEEP_MTIME_TTL_SECS: i64 = 600;
+const DEEP_MTIME_DEPTH: usize = 2;
+
+/// Newest file mtime per project, recomputed at most every `DEEP_MTIME_TTL_SECS`.
+struct DeepMtimeCache {
+    path: PathBuf,
+    entries: HashMap<PathBuf, (i64, i64)>, // path -> (newest mtime, checked at)
+}
+
+impl DeepMtimeCache {
+    fn load(path: &Path) -> Self {
+        let mut entries = HashMap::new();
+        if let Ok(content) = fs::read_to_string(path) {
+            for line in content.lines() {
+                let mut parts = line.splitn(3, '\t');
+                if let (Some(mtime), Some(checked), Some(path)) =
+                    (parts.next(), parts.next(), parts.next())
+                {
+                    if let (Ok(mtime), Ok(checked)) = (mtime.parse(), checked.parse()) {
+                        entries.insert(PathBuf::from(path), (mtime, checked));
+                    }
+                }
+            }
+        }
+        Self {
+            path: path.to_path_buf(),
+            entries,
+        }
+    }
+
+    fn save(&self) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut content = String::new();
+        for (path, (mtime, checked)) in &self.entries {
+            content.push_str(&format!("{}\t{}\t{}\n", mtime, checked, path.display()));
+        }
+        fs::write(&self.path, content)?;
+        Ok(())
+    }
+
+    fn newest(&mut self, project_path: &Path, ignore_dirs: &[String]) -> Option<DateTime<Utc>> {
+        let now = Utc::now().timestamp();
+        if let Some((mtime, checked)) = self.entries.get(project_path) {
+            if now - checked < DEEP_MTIME_TTL_SECS {
+                return DateTime::from_timestamp(*mtime, 0);
+            }
+        }
+
+        let newest = newest_file_mtime(project_path, DEEP_MTIME_DEPTH, ignore_dirs)?;
+        let mtime = DateTime::<Utc>::from(newest).timestamp();
+        self.entries
+            .insert(project_path.to_path_buf(), (mtime, now));
+        DateTime::from_timestamp(mtime, 0)
+    }
+}
+
+/// Whether each project folder holds a `.git`, keyed by the folder's mtime.
+/// Creating or removing `.git` touches the folder, so while the mtime is
+/// unchanged the answer is too, and a cold scan skips one stat per project.
+struct GitPresenceCache {
+    path: PathBuf,
+    entries: HashMap<PathBuf, (u128, bool)>, // path -> (mtime in ns, has .git)
+    seen: HashMap<PathBuf, (u128, bool)>,
+}
+
+impl GitPresenceCache {
+    fn load(path: &Path) -> Self {
+        let mut entries = HashMap::new();
+        if let Ok(content) = fs::read_to_string(path) {
+            for line in content.lines() {
+                let mut parts = line.splitn(3, '\t');
+                if let (Some(mtime), Some(git), Some(path)) = (parts.next(), parts.next(), parts.next()) {
+                    if let Ok(mtime) = mtime.parse() {
+                        entries.insert(PathBuf::from(path), (mtime, git == "1"));
+                    }
+                }
+            }
+        }
+        Self {
+            path: path.to_path_buf(),
+            entries,
+            seen: HashMap::new(),
+        }
+    }
+
+    /// Only folders seen in this scan are kept, so removed projects drop out.
+    fn save(&self) -> Result<()> {
+        if self.seen == self.entries {
+            return Ok(());
+        }
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut content = String::new();
+        for (path, (mtime, git)) in &self.seen {
+            content.push_str(&format!("{}\t{}\t{}\n", mtime, if *git { 1 } else { 0 }, path.display()));
+        }
+        fs::write(&self.path, content)?;
+        Ok(())
+    }
+
+    fn is_git_repo(&mut self, dir: &Path, metadata: &Metadata) -> bool {
+        let Some(mtime) = metadata.modified().ok().and_then(|m| m.duration_since(UNIX_EPOCH).ok()).map(|d| d.as_nanos()) else {
+            return dir.join(".git").exists();
+        };
+        let git = match self.entries.get(dir) {
+            Some((cached, git)) if *cached == mtime => *git,
+            _ => dir.join(".git").exists(),
+        };
+        self.seen.insert(dir.to_path_buf(), (mtime, git));
+        git
+    }
+}
+
+fn newest_file_mtime(dir: &Path, depth: usize, ignore_dirs: &[String]) -> Option<SystemTime> {
+    let mut newest: Option<SystemTime> = None;
+    walk_dir(dir, depth, ignore_dirs, &mut |entry| {
+        // Hidden files (including .slop_access) aren't project edits
+        if entry.file_name().to_string_lossy().starts_with('.') {
+            return;
+        }
+        if entry.file_type().is_ok_and(|t| t.is_file()) {
+            if let Ok(modified) = entry.metadata().and_then(|m| m.modified()) {
+                newest = Some(newest.map_or(modified, |n| n.max(modified)));
+            }
+        }
+    });
+    newest
+}
+
+/// Depth-limited walk that never descends into `ignore_dirs` (node_modules, target, ...).
+/// `visit` sees every file and directory that isn't ignored; unreadable dirs are skipped.
+pub fn walk_dir(
+    dir: &Path,
+    max_depth: usize,
+    ignore_dirs: &[String],
+    visit: &mut dyn FnMut(&fs::DirEntry),
+) {
+    if max_depth == 0 {
+        return;
+    }
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        let is_dir = entry.file_type().is_ok_and(|t| t.is_dir());
+        if is_dir
+            && ignore_dirs
+                .iter()
+                .any(|ignored| name.as_os_str() == ignored.as_str())
+        {
+            continue;
+        }
+
+        visit(&entry);
+        if is_dir {
+            walk_dir(&entry.path(), max_depth - 1, ignore_dirs, visit);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A fresh, empty directory under the system temp dir.
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("slop-test-{}-{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn project(name: &str) -> Project {
+        let now = Utc::now();
+        Project {
+            name: name.to_string(),
+            path: PathBuf::from("/projects").join(name),
+            root: PathBuf::from("/projects"),
+            last_accessed: now,
+            created: now,
+            score: 0.0,
+            project_type: ProjectType::Local,
+            description: None,
+        }
+    }
+
+    fn ranked(names: &[&str], query: &str) -> Vec<String> {
+        let projects: Vec<Project> = names.iter().map(|name| project(name)).collect();
+        rank_projects(&projects, query, &[]).into_iter().map(|p| p.name).collect()
+    }
+
+    fn scan(root: &Path) -> Vec<Project> {
+        let options = ScanOptions { scan_depth: 1, ..Default::default() };
+        scan_projects(&[root.to_path_buf()], &options).unwrap()
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlinked_project_keeps_link_name() {
+        use std::os::unix::fs::symlink;
+
+        let dir = temp_dir("symlink-entry");
+        let target = dir.join("elsewhere/real-name");
+        fs::create_dir_all(target.join(".git")).unwrap();
+        let root = dir.join("projects");
+        fs::create_dir_all(&root).unwrap();
+        symlink(&target, root.join("linked")).unwrap();
+
+        let projects = scan(&root);
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0].name, "linked");
+        assert_eq!(projects[0].path, root.join("linked"));
+        assert!(matches!(projects[0].project_type, ProjectType::GitRepo));
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlinked_projects_root_is_scanned() {
+        use std::os::unix::fs::symlink;
+
+        let dir = temp_dir("symlink-root");
+        fs::create_dir_all(dir.join("drive/projects/app")).unwrap();
+        symlink(dir.join("drive/projects"), dir.join("projects")).unwrap();
+
+        let projects = scan(&dir.join("projects"));
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0].name, "app");
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn broken_symlinks_are_skipped() {
+        use std::os::unix::fs::symlink;
+
+        let dir = temp_dir("symlink-broken");
+        let root = dir.join("projects");
+        fs::create_dir_all(root.join("kept")).unwrap();
+        symlink(dir.join("missing"), root.join("dangling")).unwrap();
+
+        let names: Vec<String> = scan(&root).into_iter().map(|p| p.name).collect();
+        assert_eq!(names, ["kept"]);
+
+        // A dangling projects root is an error, not an empty list
+        symlink(dir.join("unmounted"), dir.join("offline")).unwrap();
+        let err = check_base_path(&dir.join("offline")).unwrap_err();
+        assert!(err.to_string().contains("symlink target missing"));
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn query_without_separators_ranks_dashed_name_first() {
+        let ranked = ranked(&["mayhem-apps", "my-app", "my-application-platform"], "myapp");
+        assert_eq!(ranked[0], "my-app");
+    }
+
+    #[test]
+    fn scan_lists_direct_children_and_detects_git() {
+        let root = temp_dir("scan-basic");
+        fs::create_dir_all(root.join("repo/.git")).unwrap();
+        fs::create_dir_all(root.join("plain")).unwrap();
+        fs::write(root.join("notes.txt"), "").unwrap();
+
+        let mut projects = scan(&root);
+        projects.sort_by(|a, b| a.name.cmp(&b.name));
+        let names: Vec<&str> = projects.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["plain", "repo"]);
+        assert!(matches!(projects[0].project_type, ProjectType::Local));
+        assert!(matches!(projects[1].project_type, ProjectType::GitRepo));
+        fs::remove_dir_all(&root).ok();
+    }
+
+    #[test]
+    fn deeper_scan_names_nested_projects_by_relative_path() {
+        let root = temp_dir("scan-nested");
+        fs::create_dir_all(root.join("work/api")).unwrap();
+        fs::write(root.join("work/api/Cargo.toml"), "").unwrap();
+        fs::create_dir_all(root.join("work/web")).unwrap();
+        fs::write(root.join("work/web/package.json"), "{}").unwrap();
+
+        let options = ScanOptions { scan_depth: 2, ..Default::default() };
+        let mut names: Vec<String> = scan_projects(std::slice::from_ref(&root), &options)
+            .unwrap()
+            .into_iter()
+            .map(|p| p.name)
+            .collect();
+        names.sort();
+        assert_eq!(names, ["work/api", "work/web"]);
+        fs::remove_dir_all(&root).ok();
+    }
+
+    #[test]
+    fn ranking_drops_non_matches_only_when_querying() {
+        assert_eq!(ranked(&["alpha", "beta"], "alp"), ["alpha"]);
+        assert_eq!(ranked(&["alpha", "beta"], "").len(), 2);
+    }
+
+    #[test]
+    fn pinned_projects_rank_above_better_matches() {
+        let projects = vec![project("api"), project("api-gateway-old")];
+        let ranked = rank_projects(&projects, "api", &["api-gateway-old".to_string()]);
+        assert_eq!(ranked[0].name, "api-gateway-old");
+    }
+
+    #[test]
+    fn ranking_prefers_exact_then_word_start_then_scattered() {
+        assert_eq!(ranked(&["api-server", "api"], "api")[0], "api");
+        assert_eq!(ranked(&["scrapi", "api-tool"], "api")[0], "api-tool");
+        assert_eq!(ranked(&["a-lot-of-pieces-in", "apin"], "api")[0], "apin");
+    }
+
+    #[test]
+    fn match_score_stays_bounded_for_short_names() {
+        let now = Utc::now();
+        let breakdown = score_breakdown("my-app", "myapp", &now, &now);
+        assert_eq!(breakdown.matched, [0, 1, 3, 4, 5]);
+        // 5 chars, each at most 1 base + 1 boundary + 1 proximity, before the length penalty
+        assert!(breakdown.match_score > 0.0 && breakdown.match_score <= 15.0);
+        assert_eq!(score_breakdown("my-app", "xyz", &now, &now).total, 0.0);
+    }
+
+    /// `cargo test bench_score_long_name -- --ignored --nocapture`
+    #[test]
+    #[ignore]
+    fn bench_score_long_name() {
+        let name = "very-long-project-name-".repeat(9)[..200].to_string();
+        let now = Utc::now();
+        let runs = 10_000;
+        let start = std::time::Instant::now();
+        for _ in 0..runs {
+            std::hint::black_box(calculate_score(&name, "vlpnm", &now, &now));
+        }
+        println!("calculate_score on 200 chars: {:?} per call", start.elapsed() / runs);
+    }
+
+    #[test]
+    fn separators_are_optional_when_matching() {
+        for (query, name) in [("myapp", "my-app"), ("webui", "web_ui"), ("srcindex", "src/index")] {
+            let ranked = ranked(&[name, "unrelated"], query);
+            assert_eq!(ranked, [name], "{} should match {}", query, name);
+        }
+
+        // The dash still marks a word start, so "ma" scores higher on "my-app" than "myapp"
+        let now = Utc::now();
+        let dashed = score_breakdown("my-app", "ma", &now, &now).match_score;
+        let joined = score_breakdown("myapp", "ma", &now, &now).match_score;
+        assert!(dashed > joined);
+    }
+}